@@ -0,0 +1,135 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting the allocations of the threads inside [`measure`].
+pub struct Counting;
+
+/// The memory a piece of code allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    pub allocations: usize,
+    /// Every byte allocated, including those freed again.
+    pub bytes: usize,
+    /// The most heap bytes allocated and not yet freed at any one time; the stack and memory from before the
+    /// measurement are not counted.
+    pub peak: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    enabled: bool,
+    usage: Usage,
+    /// Bytes allocated minus bytes freed; negative when memory from before the measurement is freed.
+    live: isize,
+}
+
+thread_local! {
+    // no destructor, so the allocator can use it at any point of a thread's life
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters { enabled: false, usage: Usage { allocations: 0, bytes: 0, peak: 0 }, live: 0 })
+    };
+}
+
+/// Counts an allocation of `allocated` bytes that frees `freed` bytes, as a reallocation does.
+fn count(allocated: usize, freed: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        if !c.enabled {
+            return;
+        }
+        if allocated > 0 {
+            c.usage.allocations += 1;
+            c.usage.bytes += allocated;
+        }
+        c.live += allocated as isize - freed as isize;
+        c.usage.peak = c.usage.peak.max(c.live.max(0) as usize);
+        counters.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(0, layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size, layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs `f`, counting what it allocates on the current thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    COUNTERS.set(Counters { enabled: true, usage: Usage::default(), live: 0 });
+    let result = f();
+    let counters = COUNTERS.replace(Counters { enabled: false, usage: Usage::default(), live: 0 });
+    (result, counters.usage)
+}
+
+/// Runs `f`, counting what it allocates only if `enabled`, so that unmeasured runs pay nothing for it.
+pub fn measure_if<T>(enabled: bool, f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if enabled {
+        let (result, usage) = measure(f);
+        (result, Some(usage))
+    } else {
+        (f(), None)
+    }
+}
+
+/// Formats a number of bytes with a binary unit that keeps it short, e.g. `512B`, `3.5KiB` or `1.2GiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let exp = (0..units.len()).rev().find(|&i| bytes >= 1 << (10 * i)).unwrap_or(0);
+    match exp {
+        0 => format!("{}B", bytes),
+        _ => format!("{:.1}{}", bytes as f64 / (1u64 << (10 * exp)) as f64, units[exp]),
+    }
+}
+
+impl Usage {
+    pub fn report(&self) -> String {
+        format!("{} allocations, {} allocated, {} peak heap", self.allocations, format_bytes(self.bytes), format_bytes(self.peak))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alloc::*;
+
+    #[test]
+    fn test_measure() {
+        let kept = vec![0u8; 100];
+        let (v, usage) = measure(|| {
+            drop(kept);
+            let mut v = Vec::<u8>::with_capacity(1000);
+            v.extend(vec![1; 2000]);
+            v
+        });
+        assert_eq!(v.len(), 2000);
+        // growing `v` is the third allocation, while the temporary vector is alive, and the 100 bytes
+        // freed at first were allocated before
+        assert_eq!(usage, Usage { allocations: 3, bytes: 1000 + 2000 + v.capacity(), peak: 2000 + v.capacity() - 100 });
+        assert_eq!(measure(|| 1 + 1).1, Usage::default());
+        assert_eq!(measure_if(false, || vec![0u8; 10]).1, None);
+        assert_eq!(measure_if(true, || vec![0u8; 10]).1.map(|usage| usage.peak), Some(10));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(3584), "3.5KiB");
+        assert_eq!(format_bytes(5 << 30), "5.0GiB");
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use common::{ParseError, Source};
+
+/// Where `aoc verify` keeps the accepted answers, relative to the repository root.
+pub const PATH: &str = "answers.txt";
+
+const HEADER: &str = "# <day> <part> <answer>, one per line; multi-line answers are joined with \\n
+# an entry without an answer is still pending and is recorded by `aoc verify --update`";
+
+/// The accepted answer of every part for its day's `input.txt`, keyed by `(day, part)`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8), Option<String>>,
+}
+
+/// How a fresh answer compares to the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Changed { expected: String },
+    /// No answer recorded yet.
+    New,
+    /// The input could not be read or parsed, or the solution panicked.
+    Fail(String),
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let mut entries = BTreeMap::new();
+        for line in input.lines().filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            let mut split = line.splitn(3, ' ');
+            let day = src.next(&mut split, line, "a day")?;
+            let day = match day.parse::<u8>() {
+                Ok(n) if (1..=25).contains(&n) => n,
+                _ => return Err(src.error(day, "a day from 1 to 25")),
+            };
+            let part = src.next(&mut split, line, "a part")?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(src.error(part, "part 1 or 2")),
+            };
+            let answer = split.next().filter(|a| !a.is_empty()).map(unescape);
+            if entries.insert((day, part), answer).is_some() {
+                return Err(src.error(line, "one entry per day and part"));
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.entries.get(&(day, part))?.as_deref()
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: Option<String>) {
+        self.entries.insert((day, part), answer);
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Status {
+        match self.get(day, part) {
+            None => Status::New,
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Changed { expected: expected.to_string() },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for ((day, part), answer) in &self.entries {
+            match answer {
+                Some(answer) => writeln!(f, "{} {} {}", day, part, escape(answer))?,
+                None => writeln!(f, "{} {}", day, part)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Keeps an answer on one line of the manifest.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut s = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { s.push('\n'); chars.next(); }
+            ('\\', Some('\\')) => { s.push('\\'); chars.next(); }
+            _ => s.push(c),
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(10, 2, Some("#.\n.#".to_string()));
+        answers.set(1, 1, Some("70374".to_string()));
+        answers.set(6, 1, None);
+        let text = answers.to_string();
+        assert!(text.ends_with("1 1 70374\n6 1\n10 2 #.\\n.#\n"));
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("1 1 70374\n1 2\n").unwrap();
+        assert_eq!(answers.check(1, 1, "70374"), Status::Pass);
+        assert_eq!(answers.check(1, 1, "1"), Status::Changed { expected: "70374".to_string() });
+        assert_eq!(answers.check(1, 2, "1"), Status::New);
+        assert_eq!(answers.check(2, 1, "1"), Status::New);
+        assert_eq!(Answers::parse("1 1 2\n1 3 4").unwrap_err().to_string(),
+                   "line 2, column 3: expected part 1 or 2, found \"3\"");
+    }
+}
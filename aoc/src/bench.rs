@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use common::bench::{self, format_duration, Stats};
+use common::{ParseError, Source};
+
+/// The timed stages of a day, in the order they run.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// How long a single stage may keep sampling before its statistics are taken as they are.
+pub const BUDGET: Duration = Duration::from_secs(3);
+
+/// Stats of every timed stage, keyed by `(day, stage)`.
+pub type Timings = BTreeMap<(u8, &'static str), Stats>;
+
+/// Times parsing and each part of `day` on `input`.
+pub fn run(day: u8, input: &str, runs: usize) -> Result<Timings, ParseError> {
+    let solution = crate::days::DAYS[usize::from(day) - 1];
+    let mut timings = Timings::new();
+    timings.insert((day, STAGES[0]), bench::measure(runs, BUDGET, || solution.parse_any(input)));
+    let input = solution.parse_any(input)?;
+    for (part, stage) in [(1, STAGES[1]), (2, STAGES[2])] {
+        timings.insert((day, stage), bench::measure(runs, BUDGET, || solution.solve_any(input.as_ref(), part)));
+    }
+    Ok(timings)
+}
+
+/// Saves timings as one `<day> <stage> <runs> <mean> <stddev> <min>` line each, in nanoseconds.
+pub fn save(timings: &Timings) -> String {
+    timings.iter().fold(String::new(), |mut s, ((day, stage), stats)| {
+        let _ = writeln!(s, "{} {} {} {} {} {}", day, stage, stats.runs,
+                         stats.mean.as_nanos(), stats.stddev.as_nanos(), stats.min.as_nanos());
+        s
+    })
+}
+
+/// Loads timings written by [`save`].
+pub fn load(input: &str) -> Result<Timings, ParseError> {
+    let src = Source::new(input);
+    input.lines().map(|line| {
+        let mut split = line.split(' ');
+        let day = src.parse::<u8>(src.next(&mut split, line, "a day")?, "a day")?;
+        let stage = src.next(&mut split, line, "a stage")?;
+        let stage = *STAGES.iter().find(|&&s| s == stage).ok_or_else(|| src.error(stage, "parse, part1 or part2"))?;
+        let runs = src.parse::<usize>(src.next(&mut split, line, "a number of runs")?, "a number of runs")?;
+        let mut nanos = || -> Result<Duration, ParseError> {
+            let token = src.next(&mut split, line, "a duration in nanoseconds")?;
+            Ok(Duration::from_nanos(src.parse::<u64>(token, "a duration in nanoseconds")?))
+        };
+        let (mean, stddev, min) = (nanos()?, nanos()?, nanos()?);
+        Ok(((day, stage), Stats { runs, mean, stddev, min }))
+    }).collect()
+}
+
+/// Renders timings as a table, comparing the means to `baseline` when there is one.
+pub fn table(timings: &Timings, baseline: Option<&Timings>) -> String {
+    let mut s = format!("{:>3}  {:<5}  {:>4}  {:>9}  {:>9}  {:>9}", "day", "stage", "runs", "mean", "stddev", "min");
+    if baseline.is_some() {
+        let _ = write!(s, "  {:>9}  {:>7}", "baseline", "change");
+    }
+    for (&(day, stage), stats) in timings {
+        let _ = write!(s, "\n{:>3}  {:<5}  {:>4}  {:>9}  {:>9}  {:>9}", day, stage, stats.runs,
+                       format_duration(stats.mean), format_duration(stats.stddev), format_duration(stats.min));
+        if let Some(baseline) = baseline {
+            match baseline.get(&(day, stage)) {
+                Some(base) => {
+                    let change = (stats.mean.as_secs_f64() / base.mean.as_secs_f64().max(1e-9) - 1.0) * 100.0;
+                    let _ = write!(s, "  {:>9}  {:>+6.1}%", format_duration(base.mean), change);
+                }
+                None => { let _ = write!(s, "  {:>9}  {:>7}", "-", "-"); }
+            }
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    fn stats(mean: u64) -> Stats {
+        let d = Duration::from_micros;
+        Stats { runs: 10, mean: d(mean), stddev: d(1), min: d(mean - 1) }
+    }
+
+    #[test]
+    fn test_save_load() {
+        let timings = Timings::from([((1, "parse"), stats(20)), ((25, "part2"), stats(3))]);
+        let saved = save(&timings);
+        assert_eq!(saved, "1 parse 10 20000 1000 19000\n25 part2 10 3000 1000 2000\n");
+        assert_eq!(load(&saved).unwrap(), timings);
+        assert_eq!(load("1 part3 1 1 1 1").unwrap_err().to_string(), "line 1, column 3: expected parse, part1 or part2, found \"part3\"");
+    }
+
+    #[test]
+    fn test_table() {
+        let timings = Timings::from([((1, "part1"), stats(30)), ((2, "part1"), stats(5))]);
+        let baseline = Timings::from([((1, "part1"), stats(20))]);
+        let table = table(&timings, Some(&baseline));
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1], "  1  part1    10     30.0µs      1.0µs     29.0µs     20.0µs   +50.0%");
+        assert!(lines[2].ends_with("-        -"));
+    }
+}
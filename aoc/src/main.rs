@@ -0,0 +1,568 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{fs, process, thread};
+
+use answers::{Answers, Status};
+use common::{fuzz, Format, InputSource, Rng};
+
+mod alloc;
+mod answers;
+mod bench;
+mod days;
+mod run_all;
+mod scaffold;
+mod stress;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+const USAGE: &str = "usage: aoc run [--day <1-25>] [--part <1|2>] [--input <path|->] [--format <text|json>] [--memory] [--frames <dir>]
+       aoc run --example [--day <1-25>] [--part <1|2>]
+       aoc run-all [--threads <n>]
+       aoc verify [--day <1-25>] [--update]
+       aoc bench [--day <1-25>] [--runs <n>] [--save <path>] [--baseline <path>]
+       aoc new --day <1-25>
+       aoc generate --day <1-25> [--size <n>] [--seed <n>]
+       aoc stress [--day <1-25>] [--max-size <n>] [--seed <n>]
+       aoc fuzz [--day <1-25>] [--cases <n>] [--seed <n>]
+-v, -vv and -vvv log what the solvers are doing on stderr, at the info, debug and trace levels";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    /// Solves `day`, or every day when there is none.
+    /// With `memory`, what parsing and each part allocate is reported too, and with `frames`, the simulations
+    /// that can be pictured write their frames into that directory.
+    Run {
+        day: Option<u8>,
+        part: Option<u8>,
+        input: Option<String>,
+        format: Format,
+        memory: bool,
+        frames: Option<String>,
+    },
+    /// Solves the puzzle examples of `day`, or of every day, checking the answers the puzzles give for them.
+    Examples { day: Option<u8>, part: Option<u8> },
+    /// Solves every part of every day at once on `threads` threads (one per core by default), printing a summary.
+    RunAll { threads: Option<usize> },
+    /// Checks the answers of every day (or just `day`) against the answers manifest.
+    Verify { day: Option<u8>, update: bool },
+    /// Times parsing and both parts of every day (or just `day`), optionally saving or comparing to a baseline.
+    Bench { day: Option<u8>, runs: usize, save: Option<String>, baseline: Option<String> },
+    /// Generates a new day's crate from the README template.
+    New { day: u8 },
+    /// Prints a random input of `day`, the same for the same `size` and `seed`.
+    Generate { day: u8, size: usize, seed: u64 },
+    /// Solves generated inputs of doubling sizes up to `max_size` for every day (or just `day`), until one fails.
+    Stress { day: Option<u8>, max_size: usize, seed: u64 },
+    /// Feeds the parser of every day (or just `day`) `cases` random and mangled inputs, from `seed` on.
+    Fuzz { day: Option<u8>, cases: u64, seed: u64 },
+}
+
+/// The flags of every command that take a value.
+const VALUED_FLAGS: &[&str] = &[
+    "--day", "--part", "--input", "--format", "--frames", "--threads", "--runs", "--save", "--baseline", "--size",
+    "--seed", "--max-size", "--cases",
+];
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, mut rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest.iter()),
+        None => return Err("missing command".to_string()),
+    };
+    match command {
+        "run" => {
+            let (mut day, mut part, mut input, mut format, mut frames) = (None, None, None, Format::Text, None);
+            let (mut example, mut memory) = (false, false);
+            while let Some(flag) = rest.next() {
+                match flag.as_str() {
+                    "--example" => example = true,
+                    "--memory" => memory = true,
+                    _ => {}
+                }
+                if flag == "--example" || flag == "--memory" {
+                    continue;
+                }
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    "--part" => part = Some(parse_number(flag, value, 1..=2)?),
+                    "--input" => input = Some(value.clone()),
+                    "--format" => format = value.parse()?,
+                    "--frames" => frames = Some(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            if day.is_none() && input.is_some() {
+                return Err("--input needs a --day".to_string());
+            }
+            if example && input.is_some() {
+                return Err("--example and --input both name the input".to_string());
+            }
+            if (example || memory) && format != Format::Text {
+                return Err(format!("{} only prints text", if example { "--example" } else { "--memory" }));
+            }
+            if example && memory {
+                return Err("--memory is not reported for examples".to_string());
+            }
+            if example && frames.is_some() {
+                return Err("--frames is not recorded for examples".to_string());
+            }
+            if example {
+                return Ok(Command::Examples { day, part });
+            }
+            Ok(Command::Run { day, part, input, format, memory, frames })
+        }
+        "run-all" => {
+            let mut threads = None;
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--threads" => threads = Some(parse_number(flag, value, 1..=1024)?),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::RunAll { threads })
+        }
+        "verify" => {
+            let (mut day, mut update) = (None, false);
+            while let Some(flag) = rest.next() {
+                match flag.as_str() {
+                    "--day" => {
+                        let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                        day = Some(parse_number(flag, value, 1..=25)?);
+                    }
+                    "--update" => update = true,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::Verify { day, update })
+        }
+        "bench" => {
+            let (mut day, mut runs, mut save, mut baseline) = (None, 100, None, None);
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    "--runs" => runs = parse_number(flag, value, 1..=100_000)?,
+                    "--save" => save = Some(value.clone()),
+                    "--baseline" => baseline = Some(value.clone()),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::Bench { day, runs, save, baseline })
+        }
+        "new" => {
+            let mut day = None;
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            let day = day.ok_or("missing --day")?;
+            Ok(Command::New { day })
+        }
+        "generate" => {
+            let (mut day, mut size, mut seed) = (None, 100, 0);
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    "--size" => size = parse_number(flag, value, 0..=100_000_000)?,
+                    "--seed" => seed = parse_number(flag, value, 0..=u64::MAX)?,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            let day = day.ok_or("missing --day")?;
+            Ok(Command::Generate { day, size, seed })
+        }
+        "stress" => {
+            let (mut day, mut max_size, mut seed) = (None, 1024, 0);
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    "--max-size" => max_size = parse_number(flag, value, 1..=100_000_000)?,
+                    "--seed" => seed = parse_number(flag, value, 0..=u64::MAX)?,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::Stress { day, max_size, seed })
+        }
+        "fuzz" => {
+            let (mut day, mut cases, mut seed) = (None, 10_000, 0);
+            while let Some(flag) = rest.next() {
+                let value = rest.next().ok_or(format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--day" => day = Some(parse_number(flag, value, 1..=25)?),
+                    "--cases" => cases = parse_number(flag, value, 1..=u64::MAX)?,
+                    "--seed" => seed = parse_number(flag, value, 0..=u64::MAX)?,
+                    _ => return Err(format!("unknown flag {}", flag)),
+                }
+            }
+            Ok(Command::Fuzz { day, cases, seed })
+        }
+        _ => Err(format!("unknown command {}", command)),
+    }
+}
+
+/// `day`, or every day when there is none.
+fn selected_days(day: Option<u8>) -> RangeInclusive<u8> {
+    match day {
+        Some(day) => day..=day,
+        None => 1..=25,
+    }
+}
+
+/// `part`, or both parts when there is none.
+fn selected_parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn parse_number<T: FromStr + PartialOrd + Display>(flag: &str, value: &str, range: RangeInclusive<T>) -> Result<T, String> {
+    match value.parse::<T>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!("{} expects a number in {}..={}, got {:?}", flag, range.start(), range.end(), value)),
+    }
+}
+
+fn run(day: Option<u8>, part: Option<u8>, input: Option<String>, format: Format, memory: bool,
+       frames: Option<String>) -> Result<(), String> {
+    if let Some(dir) = frames {
+        common::image::record_to(Path::new(&dir)).map_err(|e| format!("{}: {}", dir, e))?;
+    }
+    for day in selected_days(day) {
+        let source = InputSource::resolve(day, input.as_deref());
+        let input = source.read()?;
+        let solution = days::DAYS[usize::from(day) - 1];
+        let (input, usage) = alloc::measure_if(memory, || solution.parse_any(&input));
+        let input = input.map_err(|e| format!("{}: {}", source, e))?;
+        if let Some(usage) = usage {
+            println!("parse: {}", usage.report());
+        }
+        for part in selected_parts(part) {
+            let start = Instant::now();
+            let (answer, usage) = alloc::measure_if(memory, || solution.solve_any(input.as_ref(), part));
+            let elapsed = start.elapsed();
+            let answer = answer.ok_or(format!("day {} has no part {}", day, part))?;
+            println!("{}", common::format_record(format, day, part, &answer, elapsed));
+            if let Some(usage) = usage {
+                println!("part{}: {}", part, usage.report());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_all(threads: Option<usize>) -> Result<(), String> {
+    let threads = threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let parts = (1..=25).flat_map(|day| [(day, 1), (day, 2)]).collect::<Vec<_>>();
+    // failures are reported in the summary
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let records = run_all::pool(&parts, threads, |&(day, part)| run_all::solve(day, part));
+    println!("{}", run_all::summary(&records, threads, start.elapsed()));
+    let failed = records.iter().filter(|record| record.answer.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} parts failed", failed));
+    }
+    Ok(())
+}
+
+fn run_examples(day: Option<u8>, part: Option<u8>) -> Result<(), String> {
+    let parts = selected_parts(part);
+    let mut wrong = 0;
+    for day in selected_days(day) {
+        let solution = days::DAYS[usize::from(day) - 1];
+        for example in solution.examples() {
+            let input = solution.parse_any(example.input).map_err(|e| format!("day {} {}: {}", day, example.name, e))?;
+            for &part in &parts {
+                let answer = solution.solve_any(input.as_ref(), part).ok_or(format!("day {} has no part {}", day, part))?;
+                let check = match example.answers[usize::from(part) - 1] {
+                    Some(expected) if expected == answer => " (as expected)".to_string(),
+                    Some(expected) => {
+                        wrong += 1;
+                        format!(" (expected {})", expected)
+                    }
+                    None => String::new(),
+                };
+                println!("day {:02} {}, {}{}", day, example.name, common::format_answer(part, &answer), check);
+            }
+        }
+    }
+    if wrong > 0 {
+        return Err(format!("{} answers differ from the examples'", wrong));
+    }
+    Ok(())
+}
+
+fn verify(day: Option<u8>, update: bool) -> Result<(), String> {
+    let mut answers = match fs::read_to_string(answers::PATH) {
+        Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", answers::PATH, e))?,
+        Err(_) => Answers::default(),
+    };
+    let (mut passed, mut changed, mut new, mut failed) = (0, 0, 0, 0);
+    for day in selected_days(day) {
+        for part in [1, 2] {
+            let record = run_all::solve(day, part);
+            let (status, answer) = match &record.answer {
+                Ok(answer) => (answers.check(day, part, answer), Some(answer)),
+                Err(e) => (Status::Fail(e.clone()), None),
+            };
+            let report = match &status {
+                Status::Pass => "pass".to_string(),
+                Status::Changed { expected } => format!("changed, got {} instead of {}",
+                                                        answers::escape(answer.unwrap()), answers::escape(expected)),
+                Status::New => "new".to_string(),
+                Status::Fail(e) => format!("fail, {}", e),
+            };
+            println!("day {:02} part {}: {}", day, part, report);
+            match status {
+                Status::Pass => passed += 1,
+                Status::Changed { .. } => changed += 1,
+                Status::New => new += 1,
+                Status::Fail(_) => failed += 1,
+            }
+            if update && status != Status::Pass {
+                if let Some(answer) = answer {
+                    answers.set(day, part, Some(answer.clone()));
+                }
+            }
+        }
+    }
+    println!("{} passed, {} changed, {} new, {} failed", passed, changed, new, failed);
+    if update {
+        fs::write(answers::PATH, answers.to_string()).map_err(|e| format!("cannot write {}: {}", answers::PATH, e))?;
+        println!("updated {}", answers::PATH);
+    } else if changed + failed > 0 {
+        return Err("some answers do not match".to_string());
+    }
+    Ok(())
+}
+
+fn run_bench(day: Option<u8>, runs: usize, save: Option<String>, baseline: Option<String>) -> Result<(), String> {
+    let baseline = match baseline {
+        Some(path) => {
+            let text = fs::read_to_string(&path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+            Some(bench::load(&text).map_err(|e| format!("{}: {}", path, e))?)
+        }
+        None => None,
+    };
+    let mut timings = bench::Timings::new();
+    for day in selected_days(day) {
+        eprintln!("benchmarking day {:02}", day);
+        let source = InputSource::resolve(day, None);
+        let input = source.read()?;
+        timings.extend(bench::run(day, &input, runs).map_err(|e| format!("{}: {}", source, e))?);
+    }
+    println!("{}", bench::table(&timings, baseline.as_ref()));
+    if let Some(path) = save {
+        fs::write(&path, bench::save(&timings)).map_err(|e| format!("cannot write {}: {}", path, e))?;
+        println!("saved {}", path);
+    }
+    Ok(())
+}
+
+fn run_stress(day: Option<u8>, max_size: usize, seed: u64) -> Result<(), String> {
+    let mut failed = vec![];
+    // the table already shows the panic messages
+    panic::set_hook(Box::new(|_| {}));
+    println!("{}", stress::HEADER);
+    for day in selected_days(day) {
+        let solution = days::DAYS[usize::from(day) - 1];
+        let mut previous = String::new();
+        for size in std::iter::successors(Some(1), |&size| Some(size * 2)).take_while(|&size| size <= max_size) {
+            let input = solution.generate_any(&mut Rng::new(seed), size);
+            if input == previous {
+                break; // the generator caps its size, so bigger sizes won't differ either
+            }
+            let outcome = stress::attempt(day, &input);
+            println!("{}", stress::row(day, size, input.len(), &outcome));
+            match outcome {
+                stress::Outcome::Solved(times) if times.iter().sum::<Duration>() > stress::SLOW && size * 2 <= max_size => {
+                    println!("{:>3}  too slow to try bigger inputs", day);
+                    break;
+                }
+                stress::Outcome::Solved(_) => {}
+                _ => {
+                    failed.push(format!("aoc generate --day {} --size {} --seed {}", day, size, seed));
+                    break;
+                }
+            }
+            previous = input;
+        }
+    }
+    if !failed.is_empty() {
+        return Err(format!("reproduce the failures with\n{}", failed.join("\n")));
+    }
+    Ok(())
+}
+
+fn run_fuzz(day: Option<u8>, cases: u64, seed: u64) -> Result<(), String> {
+    let mut failed = 0;
+    // fuzz::parser reports the panic along with the input that caused it
+    panic::set_hook(Box::new(|_| {}));
+    for day in selected_days(day) {
+        let solution = days::DAYS[usize::from(day) - 1];
+        match panic::catch_unwind(AssertUnwindSafe(|| fuzz::parser(solution, seed..seed.saturating_add(cases)))) {
+            Ok(()) => println!("day {}: ok", day),
+            Err(payload) => {
+                println!("{}", fuzz::message(payload));
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} parsers panicked", failed));
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = common::log::init(true, VALUED_FLAGS);
+    let command = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}\n{}", e, USAGE);
+        process::exit(1);
+    });
+    let result = match command {
+        Command::Run { day, part, input, format, memory, frames } => run(day, part, input, format, memory, frames),
+        Command::Examples { day, part } => run_examples(day, part),
+        Command::RunAll { threads } => run_all(threads),
+        Command::Verify { day, update } => verify(day, update),
+        Command::Bench { day, runs, save, baseline } => run_bench(day, runs, save, baseline),
+        Command::New { day } => scaffold::new_day(day),
+        Command::Generate { day, size, seed } => {
+            print!("{}", days::DAYS[usize::from(day) - 1].generate_any(&mut Rng::new(seed), size));
+            Ok(())
+        }
+        Command::Stress { day, max_size, seed } => run_stress(day, max_size, seed),
+        Command::Fuzz { day, cases, seed } => run_fuzz(day, cases, seed),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&args("run --day 7 --part 2 --input in.txt")), Ok(Command::Run {
+            day: Some(7),
+            part: Some(2),
+            input: Some("in.txt".to_string()),
+            format: Format::Text,
+            memory: false,
+            frames: None,
+        }));
+        assert_eq!(parse_args(&args("run --format json")), Ok(Command::Run {
+            day: None,
+            part: None,
+            input: None,
+            format: Format::Json,
+            memory: false,
+            frames: None,
+        }));
+        assert_eq!(parse_args(&args("run --memory --day 14 --frames out")), Ok(Command::Run {
+            day: Some(14),
+            part: None,
+            input: None,
+            format: Format::Text,
+            memory: true,
+            frames: Some("out".to_string()),
+        }));
+        assert_eq!(parse_args(&args("run --example --day 9")), Ok(Command::Examples { day: Some(9), part: None }));
+        assert_eq!(parse_args(&args("run-all")), Ok(Command::RunAll { threads: None }));
+        assert_eq!(parse_args(&args("run-all --threads 4")), Ok(Command::RunAll { threads: Some(4) }));
+        assert_eq!(parse_args(&args("verify")), Ok(Command::Verify { day: None, update: false }));
+        assert_eq!(parse_args(&args("verify --update --day 3")), Ok(Command::Verify { day: Some(3), update: true }));
+        assert_eq!(parse_args(&args("bench --runs 5 --save base.txt")),
+                   Ok(Command::Bench { day: None, runs: 5, save: Some("base.txt".to_string()), baseline: None }));
+        assert_eq!(parse_args(&args("new --day 12")), Ok(Command::New { day: 12 }));
+        assert_eq!(parse_args(&args("generate --day 20 --size 1000000")),
+                   Ok(Command::Generate { day: 20, size: 1000000, seed: 0 }));
+        assert_eq!(parse_args(&args("stress --seed 7")), Ok(Command::Stress { day: None, max_size: 1024, seed: 7 }));
+        assert_eq!(parse_args(&args("fuzz --day 5 --cases 100")), Ok(Command::Fuzz { day: Some(5), cases: 100, seed: 0 }));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("run --input in.txt")).is_err());
+        assert!(parse_args(&args("run --format yaml")).is_err());
+        assert!(parse_args(&args("run-all --threads 0")).is_err());
+        assert!(parse_args(&args("run --example --day 1 --input in.txt")).is_err());
+        assert!(parse_args(&args("run --memory --format json")).is_err());
+        assert!(parse_args(&args("run --example --frames out")).is_err());
+        assert!(parse_args(&args("run --day 26")).is_err());
+        assert!(parse_args(&args("run --day 1 --part 3")).is_err());
+        assert!(parse_args(&args("run --day 1 --part")).is_err());
+        assert!(parse_args(&args("verify --day")).is_err());
+        assert!(parse_args(&args("verify --part 1")).is_err());
+        assert!(parse_args(&args("bench --runs 0")).is_err());
+        assert!(parse_args(&args("new")).is_err());
+        assert!(parse_args(&args("generate --size 10")).is_err());
+        assert!(parse_args(&args("stress --max-size 0")).is_err());
+        assert!(parse_args(&args("fuzz --cases 0")).is_err());
+    }
+
+    #[test]
+    fn test_selection() {
+        assert_eq!(selected_days(Some(7)), 7..=7);
+        assert_eq!(selected_days(None), 1..=25);
+        assert_eq!(selected_parts(Some(2)), [2]);
+        assert_eq!(selected_parts(None), [1, 2]);
+    }
+
+    #[test]
+    fn test_days() {
+        let solution = days::DAYS[5];
+        let input = solution.parse_any("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap();
+        assert_eq!(solution.solve_any(input.as_ref(), 1), Some("5".to_string()));
+        assert_eq!(solution.solve_any(input.as_ref(), 2), Some("23".to_string()));
+        assert!(days::DAYS.iter().enumerate().all(|(i, solution)| usize::from(solution.day()) == i + 1));
+        for solution in days::DAYS {
+            assert!(!solution.examples().is_empty());
+            for example in solution.examples() {
+                assert!(solution.parse_any(example.input).is_ok(), "day {} rejects {}", solution.day(), example.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        for solution in days::DAYS {
+            fuzz::parser(solution, 0..200);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for solution in days::DAYS {
+            let day = solution.day();
+            // part 2 of day 19 takes minutes on any blueprint, its example's tests cover it
+            let (size, parts) = if day == 19 { (1, 1..=1) } else { (8, 1..=2) };
+            let input = solution.generate_any(&mut Rng::new(1), size);
+            assert_eq!(input, solution.generate_any(&mut Rng::new(1), size), "day {} generates another input", day);
+            let input = solution.parse_any(&input).unwrap_or_else(|e| panic!("day {} rejects its input: {}", day, e));
+            for part in parts {
+                assert!(solution.solve_any(input.as_ref(), part).is_some());
+            }
+        }
+    }
+}
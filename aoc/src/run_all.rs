@@ -0,0 +1,114 @@
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::bench::format_duration;
+use common::{fuzz, InputSource};
+
+/// How many of the slowest parts the summary marks.
+const SLOWEST: usize = 3;
+
+/// The answer to a part of a day, or why there is none, and how long solving it took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Runs `work` on every job on up to `threads` threads, each taking the next job as soon as it is done
+/// with one, and returns the results in the order of `jobs`.
+pub fn pool<T: Sync, R: Send>(jobs: &[T], threads: usize, work: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let mut results = thread::scope(|scope| {
+        let workers = (0..threads.clamp(1, jobs.len().max(1))).map(|_| scope.spawn(|| {
+            let take = || {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                jobs.get(i).map(|job| (i, job))
+            };
+            let mut done = vec![];
+            while let Some((i, job)) = take() {
+                done.push((i, work(job)));
+            }
+            done
+        })).collect::<Vec<_>>();
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect::<Vec<_>>()
+    });
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Reads, parses and solves one part of `day`; each part parses its own copy of the input so that the
+/// parts of a day can run at the same time.
+pub fn solve(day: u8, part: u8) -> Record {
+    let solution = crate::days::DAYS[usize::from(day) - 1];
+    let source = InputSource::resolve(day, None);
+    let start = Instant::now();
+    let answer = source.read().and_then(|input| {
+        let input = solution.parse_any(&input).map_err(|e| format!("{}: {}", source, e))?;
+        panic::catch_unwind(AssertUnwindSafe(|| solution.solve_any(input.as_ref(), part).unwrap()))
+            .map_err(|payload| format!("panicked: {}", fuzz::message(payload)))
+    });
+    Record { day, part, answer, elapsed: start.elapsed() }
+}
+
+/// The records as a table, slowest first with the slowest few marked, followed by the total time spent
+/// solving and the `wall` time it took on `threads` threads.
+pub fn summary(records: &[Record], threads: usize, wall: Duration) -> String {
+    let mut records = records.iter().collect::<Vec<_>>();
+    records.sort_by_key(|record| (std::cmp::Reverse(record.elapsed), record.day, record.part));
+    let total = records.iter().map(|record| record.elapsed).sum::<Duration>();
+    let mut s = format!("   {:>3}  {:>4}  {:>9}  {:>5}  answer", "day", "part", "time", "share");
+    for (i, record) in records.iter().enumerate() {
+        let share = record.elapsed.as_secs_f64() / total.as_secs_f64().max(1e-9) * 100.0;
+        let answer = match &record.answer {
+            Ok(answer) => answer.replace('\n', " / "),
+            Err(e) => format!("error: {}", e),
+        };
+        let _ = write!(s, "\n{:<3}{:>3}  {:>4}  {:>9}  {:>4.1}%  {}", if i < SLOWEST { " *" } else { "" },
+                       record.day, record.part, format_duration(record.elapsed), share, answer);
+    }
+    let _ = write!(s, "\n{} parts solved in {} on {} thread{}, {} of solving in all", records.len(),
+                   format_duration(wall), threads, if threads == 1 { "" } else { "s" }, format_duration(total));
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::run_all::*;
+
+    #[test]
+    fn test_pool() {
+        let jobs = (0..100).collect::<Vec<u64>>();
+        assert_eq!(pool(&jobs, 8, |&n| n * n), jobs.iter().map(|n| n * n).collect::<Vec<_>>());
+        let threads = pool(&jobs, 4, |_| thread::current().id());
+        assert!(threads.iter().all(|id| *id != thread::current().id()));
+        assert!(pool(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+
+    #[test]
+    fn test_summary() {
+        let record = |day, part, answer: Result<&str, &str>, ms| Record {
+            day,
+            part,
+            answer: answer.map(str::to_string).map_err(str::to_string),
+            elapsed: Duration::from_millis(ms),
+        };
+        let records = [
+            record(1, 1, Ok("24000"), 10),
+            record(1, 2, Ok("45000"), 30),
+            record(10, 2, Ok("##.\n.##"), 20),
+            record(19, 2, Err("panicked: boom"), 40),
+        ];
+        let summary = summary(&records, 4, Duration::from_millis(50));
+        let lines = summary.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "   day  part       time  share  answer");
+        assert_eq!(lines[1], " *  19     2     40.0ms  40.0%  error: panicked: boom");
+        assert_eq!(lines[3], " *  10     2     20.0ms  20.0%  ##. / .##");
+        assert_eq!(lines[4], "     1     1     10.0ms  10.0%  24000");
+        assert_eq!(lines[5], "4 parts solved in 50.0ms on 4 threads, 100.0ms of solving in all");
+    }
+}
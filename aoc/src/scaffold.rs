@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::Path;
+
+use crate::answers::{self, Answers};
+
+/// The README, whose `# Template` section holds the source of a new day.
+const README: &str = include_str!("../../README.md");
+
+const MANIFEST: &str = "[package]
+name = \"dayNN\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+common = { path = \"../common\" }
+";
+
+/// The code block following the `` `<file>`: `` line of the README template, for `day`.
+pub fn template(readme: &str, file: &str, day: u8) -> Option<String> {
+    let (_, section) = readme.split_once("# Template")?;
+    let (_, rest) = section.split_once(&format!("`{}`:", file))?;
+    let (_, rest) = rest.split_once("```rust\n")?;
+    let (code, _) = rest.split_once("```")?;
+    Some(code.replace("= NN;", &format!("= {};", day)).replace("NN", &format!("{:02}", day)))
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the days in order.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let start = manifest.find("members = [").ok_or("no workspace members in Cargo.toml")? + "members = [".len();
+    let end = start + manifest[start..].find(']').ok_or("unterminated workspace members in Cargo.toml")?;
+    let mut members = manifest[start..end].split(',').map(str::trim).filter(|m| !m.is_empty()).collect::<Vec<_>>();
+    let quoted = format!("{:?}", member);
+    if members.contains(&quoted.as_str()) {
+        return Ok(manifest.to_string());
+    }
+    members.push(&quoted);
+    members.sort_unstable();
+    let members = members.iter().map(|m| format!("\n    {},", m)).collect::<String>();
+    Ok(format!("{}{}\n{}", &manifest[..start], members, &manifest[end..]))
+}
+
+/// Creates `dayNN` from the README template and registers it everywhere a day needs to be known.
+pub fn new_day(day: u8) -> Result<(), String> {
+    let name = format!("day{:02}", day);
+    let dir = Path::new(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", name));
+    }
+    let lib = template(README, "src/lib.rs", day).ok_or("no src/lib.rs template in README.md")?;
+    let main = template(README, "src/main.rs", day).ok_or("no src/main.rs template in README.md")?;
+
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    };
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("cannot create {}: {}", name, e))?;
+    write(&dir.join("Cargo.toml"), &MANIFEST.replace("NN", &format!("{:02}", day)))?;
+    write(&dir.join("src/lib.rs"), &lib)?;
+    write(&dir.join("src/main.rs"), &main)?;
+    write(&dir.join("input.txt"), "")?;
+
+    let manifest = fs::read_to_string("Cargo.toml").map_err(|e| format!("cannot read Cargo.toml: {}", e))?;
+    write(Path::new("Cargo.toml"), &add_member(&manifest, &name)?)?;
+
+    let mut answers = match fs::read_to_string(answers::PATH) {
+        Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", answers::PATH, e))?,
+        Err(_) => Answers::default(),
+    };
+    answers.set(day, 1, None);
+    answers.set(day, 2, None);
+    write(Path::new(answers::PATH), &answers.to_string())?;
+
+    println!("created {}, paste the puzzle input into {}/input.txt and the example into its tests", name, name);
+    let registered = fs::read_to_string("aoc/src/days.rs").is_ok_and(|days| days.contains(&format!("&{}::", name)));
+    if !registered {
+        println!("to run it with aoc, add it to aoc/Cargo.toml and aoc/src/days.rs");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_template() {
+        let main = template(README, "src/main.rs", 7).unwrap();
+        assert_eq!(main, "use day07::Day07;\n\nfn main() {\n    common::main::<Day07>();\n}\n");
+        let lib = template(README, "src/lib.rs", 7).unwrap();
+        assert!(lib.contains("pub struct Day07;") && lib.contains("const DAY: u8 = 7;"));
+        assert!(lib.contains("const INPUT: &str = \"\";"));
+        assert_eq!(template(README, "src/other.rs", 7), None);
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n]\n\n[profile.test]\n";
+        let added = add_member(manifest, "day02").unwrap();
+        assert_eq!(added, "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\n\n[profile.test]\n");
+        assert_eq!(add_member(&added, "day02").unwrap(), added);
+        assert!(add_member("[package]\n", "day02").is_err());
+    }
+}
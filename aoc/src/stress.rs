@@ -0,0 +1,72 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use common::bench::format_duration;
+use common::fuzz::message;
+
+/// How a day fared on one generated input.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    /// Parsing, part 1 and part 2 took this long.
+    Solved([Duration; 3]),
+    /// The parser rejected an input its own generator produced.
+    Rejected(String),
+    Panicked(String),
+}
+
+/// A day is not tried on bigger inputs once solving one takes longer than this.
+pub const SLOW: Duration = Duration::from_secs(10);
+
+pub const HEADER: &str = "day      size       bytes      parse      part1      part2";
+
+/// Parses and solves `input` with `day`, catching panics.
+pub fn attempt(day: u8, input: &str) -> Outcome {
+    let solution = crate::days::DAYS[usize::from(day) - 1];
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| solution.parse_any(input))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return Outcome::Rejected(e.to_string()),
+        Err(payload) => return Outcome::Panicked(message(payload)),
+    };
+    let mut times = [start.elapsed(), Duration::ZERO, Duration::ZERO];
+    for part in [1, 2] {
+        let start = Instant::now();
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| solution.solve_any(parsed.as_ref(), part))) {
+            return Outcome::Panicked(message(payload));
+        }
+        times[usize::from(part)] = start.elapsed();
+    }
+    Outcome::Solved(times)
+}
+
+/// A line of the stress table, lined up under [`HEADER`].
+pub fn row(day: u8, size: usize, bytes: usize, outcome: &Outcome) -> String {
+    let prefix = format!("{:>3}  {:>8}  {:>10}", day, size, bytes);
+    match outcome {
+        Outcome::Solved(times) => format!("{}  {:>9}  {:>9}  {:>9}", prefix,
+                                          format_duration(times[0]), format_duration(times[1]), format_duration(times[2])),
+        Outcome::Rejected(e) => format!("{}  rejected: {}", prefix, e),
+        Outcome::Panicked(e) => format!("{}  panicked: {}", prefix, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stress::*;
+
+    #[test]
+    fn test_attempt() {
+        assert!(matches!(attempt(6, "abcdefghijklmn"), Outcome::Solved(_)));
+        assert_eq!(attempt(1, "x"), Outcome::Rejected("line 1, column 1: expected a calorie count, found \"x\"".to_string()));
+        // a single tile is no cube net
+        assert!(matches!(attempt(22, ".\n\n1"), Outcome::Rejected(_)));
+    }
+
+    #[test]
+    fn test_row() {
+        let solved = Outcome::Solved([Duration::from_micros(5), Duration::from_millis(12), Duration::from_secs(2)]);
+        assert_eq!(row(7, 64, 1234, &solved), "  7        64        1234      5.0µs     12.0ms      2.00s");
+        assert_eq!(HEADER.len(), row(7, 64, 1234, &solved).chars().count());
+        assert_eq!(row(7, 64, 1234, &Outcome::Panicked("boom".to_string())), "  7        64        1234  panicked: boom");
+    }
+}
@@ -0,0 +1,74 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Timing summary of repeated runs of the same code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let nanos = samples.iter().map(|d| d.as_nanos() as f64).collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+        Stats {
+            runs: samples.len(),
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: *samples.iter().min().unwrap(),
+        }
+    }
+}
+
+/// Runs `f` up to `max_runs` times, stopping early once `budget` is spent, but always at least once.
+pub fn measure<T>(max_runs: usize, budget: Duration, mut f: impl FnMut() -> T) -> Stats {
+    let start = Instant::now();
+    let mut samples = vec![];
+    while samples.is_empty() || (samples.len() < max_runs && start.elapsed() < budget) {
+        let t = Instant::now();
+        black_box(f());
+        samples.push(t.elapsed());
+    }
+    Stats::from_samples(&samples)
+}
+
+/// Formats a duration with a unit that keeps it short, e.g. `812ns`, `35.2µs` or `1.50s`.
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos() as f64;
+    match ns {
+        _ if ns < 1e3 => format!("{}ns", ns),
+        _ if ns < 1e6 => format!("{:.1}µs", ns / 1e3),
+        _ if ns < 1e9 => format!("{:.1}ms", ns / 1e6),
+        _ => format!("{:.2}s", ns / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.mean.as_micros(), 5);
+        assert_eq!(stats.stddev.as_micros(), 2);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(measure(3, Duration::from_secs(10), || 1).runs, 3);
+        assert_eq!(measure(3, Duration::ZERO, || 1).runs, 1);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(812)), "812ns");
+        assert_eq!(format_duration(Duration::from_nanos(35_240)), "35.2µs");
+        assert_eq!(format_duration(Duration::from_millis(12)), "12.0ms");
+        assert_eq!(format_duration(Duration::from_millis(1500)), "1.50s");
+    }
+}
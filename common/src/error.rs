@@ -0,0 +1,135 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed puzzle input: where it went wrong, what the parser expected and what it found instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending token, empty when the line ended too early.
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The whole puzzle input, used to turn tokens sliced out of it into [`ParseError`]s with a position.
+///
+/// Every token passed in must be a subslice of the input (e.g. from `lines`, `split` or `trim`),
+/// its position is derived from its address.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// An error pointing at `token`.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize).saturating_sub(self.input.as_ptr() as usize).min(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: token.lines().next().unwrap_or("").to_string(),
+        }
+    }
+
+    /// An error pointing just past the end of `text`, for tokens that are missing.
+    pub fn missing(&self, text: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&text[text.len()..], expected)
+    }
+
+    /// Parses `token` with [`FromStr`], reporting `expected` on failure.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| self.error(token, expected))
+    }
+
+    /// Takes the next token of `line`, reporting `expected` at the end of the line if there is none.
+    pub fn next(&self, tokens: &mut impl Iterator<Item = &'a str>, line: &'a str, expected: &str) -> Result<&'a str, ParseError> {
+        tokens.next().ok_or_else(|| self.missing(line, expected))
+    }
+
+    /// Checks every character of `text` with `valid`, reporting the first one that fails.
+    pub fn check_chars(&self, text: &str, valid: impl Fn(char) -> bool, expected: &str) -> Result<(), ParseError> {
+        match text.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((i, c)) => Err(self.error(&text[i..i + c.len_utf8()], expected)),
+            None => Ok(()),
+        }
+    }
+
+    /// Strips `prefix` off `text`, reporting it as expected if it is not there.
+    pub fn prefix(&self, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        text.strip_prefix(prefix).ok_or_else(|| self.error(text, format!("{:?}", prefix)))
+    }
+
+    /// Splits `text` once at `delimiter`, reporting it as expected at the end of `text` if it is missing.
+    pub fn split_once(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter).ok_or_else(|| self.missing(text, format!("{:?}", delimiter)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "move 1 from 2 to 1\nmove x from 1 to 3\nmove 2";
+
+    #[test]
+    fn test_error_position() {
+        let src = Source::new(INPUT);
+        let line = INPUT.lines().nth(1).unwrap();
+        let e = src.parse::<usize>(&line[5..6], "a crate count").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+        assert_eq!(e.to_string(), "line 2, column 6: expected a crate count, found \"x\"");
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let src = Source::new(INPUT);
+        let line = INPUT.lines().nth(2).unwrap();
+        let mut tokens = line.split(' ');
+        assert_eq!(src.next(&mut tokens, line, "\"move\""), Ok("move"));
+        assert_eq!(src.next(&mut tokens, line, "a crate count"), Ok("2"));
+        let e = src.next(&mut tokens, line, "\"from\"").unwrap_err();
+        assert_eq!(e.to_string(), "line 3, column 7: expected \"from\", found end of line");
+    }
+
+    #[test]
+    fn test_prefix_and_split() {
+        let src = Source::new(INPUT);
+        let line = INPUT.lines().next().unwrap();
+        assert_eq!(src.prefix(line, "move "), Ok("1 from 2 to 1"));
+        assert_eq!(src.prefix(line, "take ").unwrap_err().column, 1);
+        assert_eq!(src.split_once(line, " to "), Ok(("move 1 from 2", "1")));
+        assert_eq!(src.split_once(line, " via ").unwrap_err().column, 19);
+    }
+
+    #[test]
+    fn test_check_chars() {
+        let src = Source::new("ab\ncé1");
+        let line = "ab\ncé1".lines().nth(1).unwrap();
+        assert_eq!(src.check_chars(line, |c| c.is_alphabetic(), "a letter"), Err(src.error(&line[3..], "a letter")));
+        let e = src.check_chars(line, |c| c.is_ascii(), "ASCII").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 2, "é"));
+    }
+}
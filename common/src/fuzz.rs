@@ -0,0 +1,89 @@
+use std::any::Any;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{AnySolution, Rng};
+
+/// The message a panic was raised with.
+pub fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap_or(&"unknown panic").to_string(),
+    }
+}
+
+/// Checks `property` against the random cases drawn from `Rng::new(seed)` for every seed in `seeds`,
+/// panicking with the seed of the first case that fails so that it can be replayed.
+pub fn check(seeds: Range<u64>, mut property: impl FnMut(&mut Rng)) {
+    for seed in seeds {
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed)))) {
+            panic!("property failed for seed {}: {}", seed, message(payload));
+        }
+    }
+}
+
+/// Up to `len` arbitrary bytes, with the invalid UTF-8 replaced as a file read into a `String` would be.
+pub fn bytes(rng: &mut Rng, len: usize) -> String {
+    let bytes = (0..rng.range(0..=len)).map(|_| rng.range(0..=u8::MAX)).collect::<Vec<_>>();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// `input` with a few characters replaced, removed or inserted, mostly ones taken from `input` itself so
+/// that the result stays close to something a parser accepts.
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+    let alphabet = if chars.is_empty() { vec!['\n'] } else { chars.clone() };
+    let others = [' ', '\n', '-', '0', '9', 'x', 'é'];
+    for _ in 0..rng.range(1..=3) {
+        let letters = if rng.chance(0.8) { &alphabet[..] } else { &others };
+        let c = *rng.choose(letters);
+        let at = rng.range(0..=chars.len());
+        match rng.range(0..=3) {
+            0 if at < chars.len() => chars[at] = c,
+            1 if at < chars.len() => {
+                let len = rng.range(1..=chars.len() - at);
+                chars.drain(at..at + len);
+            }
+            2 => chars.truncate(at),
+            _ => chars.insert(at, c),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Feeds the parser of `solution` arbitrary bytes and mutations of its generated inputs, one of each
+/// for every seed in `seeds`, panicking with the input of the first one it panics on instead of
+/// returning an error.
+pub fn parser(solution: &dyn AnySolution, seeds: Range<u64>) {
+    for seed in seeds {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(0..=10);
+        let generated = solution.generate_any(&mut rng, size);
+        for input in [bytes(&mut rng, 64), mutate(&mut rng, &generated)] {
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| solution.parse_any(&input))) {
+                panic!("day {} panicked parsing {:?} (seed {}): {}", solution.day(), input, seed, message(payload));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fuzz::*;
+
+    #[test]
+    fn test_check() {
+        check(0..100, |rng| assert!(rng.range(1..=6) <= 6));
+        let failure = panic::catch_unwind(|| check(0..100, |rng| assert_ne!(rng.range(1..=6), 6))).unwrap_err();
+        assert!(message(failure).starts_with("property failed for seed "));
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = Rng::new(5);
+        let inputs = (0..100).map(|_| mutate(&mut rng, "1-2,3-4\n")).collect::<Vec<_>>();
+        assert!(inputs.iter().filter(|&input| input != "1-2,3-4\n").count() > 90);
+        assert!(inputs.iter().any(|input| input.len() < 8) && inputs.iter().any(|input| input.len() > 8));
+        assert!(bytes(&mut rng, 10).chars().count() <= 10);
+    }
+}
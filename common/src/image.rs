@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A pixel's red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0x0f, 0x0f, 0x23];
+pub const WHITE: Rgb = [0xcc, 0xcc, 0xcc];
+pub const GOLD: Rgb = [0xff, 0xcc, 0x00];
+pub const GREEN: Rgb = [0x00, 0x99, 0x00];
+pub const BLUE: Rgb = [0x66, 0x99, 0xff];
+
+/// The side in pixels that small pictures are scaled up to, a whole number of pixels per cell.
+const SIDE: usize = 512;
+
+/// How many frames a simulation writes at most, so that a long one does not fill the disk.
+pub const MAX_FRAMES: usize = 2000;
+
+/// Where frames are written, and how many each simulation has written so far.
+struct Recorder {
+    dir: PathBuf,
+    counts: HashMap<String, usize>,
+}
+
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// A `width` by `height` picture of cells as a binary PPM, each cell a square of `scale` by `scale`
+/// pixels of the color `pixel` gives its `(row, col)`.
+pub fn ppm(width: usize, height: usize, scale: usize, pixel: impl Fn((usize, usize)) -> Rgb) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", width * scale, height * scale).into_bytes();
+    for row in 0..height {
+        let line = (0..width).flat_map(|col| pixel((row, col)).repeat(scale)).collect::<Vec<_>>();
+        for _ in 0..scale {
+            image.extend_from_slice(&line);
+        }
+    }
+    image
+}
+
+/// The pixels per cell that bring the longer side of a `width` by `height` picture closest to [`SIDE`].
+pub fn scale(width: usize, height: usize) -> usize {
+    (SIDE / width.max(height).max(1)).max(1)
+}
+
+/// Writes the frames of the simulations that record them into `dir` from now on, creating it if needed.
+pub fn record_to(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    *RECORDER.lock().unwrap() = Some(Recorder { dir: dir.to_path_buf(), counts: HashMap::new() });
+    Ok(())
+}
+
+/// Whether frames are being written, to skip preparing one when they are not.
+pub fn recording() -> bool {
+    RECORDER.lock().unwrap().is_some()
+}
+
+/// Writes the next frame of the simulation `name`, as `name-00001.ppm` and so on, if frames are being
+/// recorded. A frame that cannot be written stops the recording with a warning, as the solver goes on.
+pub fn frame(name: &str, width: usize, height: usize, pixel: impl Fn((usize, usize)) -> Rgb) {
+    let mut recorder = RECORDER.lock().unwrap();
+    let Some(Recorder { dir, counts }) = recorder.as_mut() else { return; };
+    let count = counts.entry(name.to_string()).or_insert(0);
+    *count += 1;
+    if *count > MAX_FRAMES {
+        if *count == MAX_FRAMES + 1 {
+            crate::warn!("{}: only the first {} frames are written", name, MAX_FRAMES);
+        }
+        return;
+    }
+    let path = dir.join(format!("{}-{:05}.ppm", name, count));
+    if let Err(e) = fs::write(&path, ppm(width, height, scale(width, height), pixel)) {
+        crate::warn!("stopped recording frames, writing {} failed: {}", path.display(), e);
+        *recorder = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::*;
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm(2, 1, 2, |(_, col)| if col == 0 { BLACK } else { WHITE });
+        let (header, pixels) = image.split_at(11);
+        assert_eq!(header, b"P6\n4 2\n255\n");
+        assert_eq!(pixels, [BLACK, BLACK, WHITE, WHITE, BLACK, BLACK, WHITE, WHITE].concat());
+        assert_eq!(scale(7, 50), 10);
+        assert_eq!(scale(1000, 10), 1);
+    }
+
+    #[test]
+    fn test_frame() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        frame("test", 1, 1, |_| GOLD); // not recording yet
+        record_to(&dir).unwrap();
+        assert!(recording());
+        // wide enough not to be scaled up, so that the frames stay small
+        for _ in 0..MAX_FRAMES + 5 {
+            frame("test", SIDE + 1, 1, |_| GOLD);
+        }
+        let mut names = fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name()).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names.len(), MAX_FRAMES);
+        assert_eq!(names[0], "test-00001.ppm");
+        assert_eq!(fs::read(dir.join("test-00002.ppm")).unwrap(), ppm(SIDE + 1, 1, 1, |_| GOLD));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{env, fmt, fs};
+
+/// Environment variable naming a directory that holds every day's input as `dayNN.txt`.
+pub const INPUTS_DIR: &str = "AOC_INPUTS";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Resolves the input of `day`: `arg` if given (`-` meaning stdin), then the [`INPUTS_DIR`] directory if it
+    /// holds the day's input, then the day's own `input.txt`.
+    pub fn resolve(day: u8, arg: Option<&str>) -> Self {
+        Self::locate(day, arg, env::var_os(INPUTS_DIR).map(PathBuf::from))
+    }
+
+    fn locate(day: u8, arg: Option<&str>, inputs_dir: Option<PathBuf>) -> Self {
+        match (arg, inputs_dir.map(|dir| dir.join(format!("day{:02}.txt", day)))) {
+            (Some("-"), _) => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(PathBuf::from(path)),
+            (None, Some(path)) if path.exists() => InputSource::File(path),
+            (None, _) => {
+                // `dayNN/input.txt` from the repository root, `input.txt` from inside the day's crate
+                let path = PathBuf::from(format!("day{:02}/input.txt", day));
+                InputSource::File(if path.exists() { path } else { PathBuf::from("input.txt") })
+            }
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        let mut input = String::new();
+        match self {
+            InputSource::Stdin => io::stdin().read_to_string(&mut input).map(|_| input),
+            InputSource::File(path) => fs::read_to_string(path),
+        }.map_err(|e| format!("cannot read {}: {}", self, e))
+    }
+
+    /// Opens the input to be read a piece at a time, for inputs too large to [`read`](Self::read) at once.
+    pub fn open(&self) -> Result<Box<dyn Read>, String> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin())),
+            InputSource::File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(file)),
+                Err(e) => Err(format!("cannot read {}: {}", self, e)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+
+    #[test]
+    fn test_locate() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day07.txt"), "").unwrap();
+        let inputs = Some(dir.clone());
+        assert_eq!(InputSource::locate(7, Some("-"), inputs.clone()), InputSource::Stdin);
+        assert_eq!(InputSource::locate(7, Some("in.txt"), inputs.clone()), InputSource::File(PathBuf::from("in.txt")));
+        assert_eq!(InputSource::locate(7, None, inputs.clone()), InputSource::File(dir.join("day07.txt")));
+        // a day missing from the directory falls back to its own input; the tests run inside the crate
+        // directory, where there is no `day08/input.txt`
+        assert_eq!(InputSource::locate(8, None, inputs), InputSource::File(PathBuf::from("input.txt")));
+        assert_eq!(InputSource::locate(7, None, None), InputSource::File(PathBuf::from("input.txt")));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read() {
+        let e = InputSource::File(PathBuf::from("missing/input.txt")).read().unwrap_err();
+        assert!(e.starts_with("cannot read missing/input.txt: "));
+        let e = InputSource::File(PathBuf::from("missing/input.txt")).open().err().unwrap();
+        assert!(e.starts_with("cannot read missing/input.txt: "));
+        assert_eq!(InputSource::Stdin.to_string(), "stdin");
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ParseError, Source};
+
+/// The primitive integers an [`Interval`] can be made of.
+pub trait Integer: Copy + Ord + fmt::Debug + fmt::Display + FromStr {
+    /// The next integer, unless `self` is the largest.
+    fn succ(self) -> Option<Self>;
+    /// The previous integer, unless `self` is the smallest.
+    fn pred(self) -> Option<Self>;
+    /// `self` as an `i128`, which holds every value of the integers up to 64 bits.
+    fn wide(self) -> i128;
+}
+
+macro_rules! integer {
+    ($($t:ty)*) => {$(
+        impl Integer for $t {
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn wide(self) -> i128 {
+                self as i128
+            }
+        }
+    )*};
+}
+
+integer!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// The integers from `start` to `end`, both included; empty when `start` is past `end`, like `start..=end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// How many integers the interval holds, which for a full `u64` or `i64` range is `2^64`.
+    pub fn len(&self) -> u128 {
+        if self.is_empty() { 0 } else { (self.end.wide() - self.start.wide() + 1) as u128 }
+    }
+
+    /// Whether `value` is in the interval.
+    pub fn includes(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every integer of `other` is in the interval.
+    pub fn contains(&self, other: &Self) -> bool {
+        other.is_empty() || self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether some integer is in both intervals.
+    pub fn overlap(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Whether the intervals overlap or one starts right after the other ends, so that their union is an interval.
+    pub fn touches(&self, other: &Self) -> bool {
+        !self.is_empty() && !other.is_empty()
+            && self.start.wide() <= other.end.wide() + 1 && other.start.wide() <= self.end.wide() + 1
+    }
+
+    /// The integers in both intervals, empty if there are none.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Interval { start: max(self.start, other.start), end: min(self.end, other.end) }
+    }
+
+    /// The integers in either interval, if they make an interval rather than two with a gap between them.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if other.is_empty() {
+            Some(*self)
+        } else if self.is_empty() {
+            Some(*other)
+        } else if self.touches(other) {
+            Some(Interval { start: min(self.start, other.start), end: max(self.end, other.end) })
+        } else {
+            None
+        }
+    }
+
+    /// The integers of the interval not in `other`: none, one interval, or the two on either side of `other`.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return vec![];
+        }
+        if !self.overlap(other) {
+            return vec![*self];
+        }
+        let mut pieces = vec![];
+        // other starts after self does and ends before self does, so neither bound is at the limit of `T`
+        if self.start < other.start {
+            pieces.push(Interval { start: self.start, end: other.start.pred().unwrap() });
+        }
+        if other.end < self.end {
+            pieces.push(Interval { start: other.end.succ().unwrap(), end: self.end });
+        }
+        pieces
+    }
+
+    /// The fewest intervals, in order and neither overlapping nor touching, holding the integers of `intervals`.
+    pub fn merge(intervals: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut intervals = intervals.into_iter().filter(|interval| !interval.is_empty()).collect::<Vec<_>>();
+        intervals.sort_unstable();
+        let mut merged: Vec<Self> = vec![];
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if last.touches(&interval) => last.end = max(last.end, interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        merged
+    }
+
+    /// Parses `start-end`, where `s` is a slice of the input behind `src`, so errors point into the whole input.
+    /// `expected` names the bounds in errors.
+    pub fn parse<'a>(src: &Source<'a>, s: &'a str, expected: &str) -> Result<Self, ParseError> {
+        // the bounds are split at the first `-` that is not the sign of the start
+        let sign = usize::from(s.starts_with('-'));
+        let (start, end) = src.split_once(&s[sign..], "-")?;
+        let start = &s[..sign + start.len()];
+        Ok(Interval { start: src.parse(start, expected)?, end: src.parse(end, expected)? })
+    }
+}
+
+/// `start-end`, e.g. `2-4` or `-5--3`, which [`FromStr`] parses back.
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl<T: Integer> FromStr for Interval<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::parse(&Source::new(s), s, "an integer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fuzz;
+    use crate::interval::*;
+
+    #[test]
+    fn test_algebra() {
+        let i = |start: i32, end: i32| Interval::new(start, end);
+        assert_eq!(i(2, 8).intersection(&i(5, 12)), i(5, 8));
+        assert!(i(2, 4).intersection(&i(6, 8)).is_empty());
+        assert_eq!(i(2, 4).union(&i(5, 8)), Some(i(2, 8)));
+        assert_eq!(i(2, 4).union(&i(6, 8)), None);
+        assert_eq!(i(2, 4).union(&i(5, 3)), Some(i(2, 4)));
+        assert_eq!(i(2, 8).difference(&i(4, 5)), [i(2, 3), i(6, 8)]);
+        assert_eq!(i(2, 8).difference(&i(0, 5)), [i(6, 8)]);
+        assert!(i(2, 8).difference(&i(2, 8)).is_empty());
+        assert_eq!(i(-3, 3).len(), 7);
+        assert_eq!(i(3, 2).len(), 0);
+        assert_eq!(Interval::new(u64::MIN, u64::MAX).len(), 1 << 64);
+        assert_eq!(Interval::new(0u8, 255).difference(&Interval::new(0, 254)), [Interval::new(255, 255)]);
+        assert_eq!(Interval::merge([i(5, 7), i(1, 2), i(9, 9), i(3, 4), i(6, 8), i(4, 1)]), [i(1, 9)]);
+        assert_eq!(Interval::merge([i(1, 2), i(4, 5)]), [i(1, 2), i(4, 5)]);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("-5--3".parse::<Interval<i64>>().unwrap(), Interval::new(-5, -3));
+        assert_eq!("2-4".parse::<Interval<u8>>().unwrap(), Interval::new(2, 4));
+        assert_eq!("2-256".parse::<Interval<u8>>().unwrap_err().to_string(),
+                   "line 1, column 3: expected an integer, found \"256\"");
+        assert_eq!("-5".parse::<Interval<i32>>().unwrap_err().to_string(),
+                   "line 1, column 3: expected \"-\", found end of line");
+        fuzz::check(0..1000, |rng| {
+            let interval = Interval::new(rng.range(i64::MIN..=i64::MAX), rng.range(i64::MIN..=i64::MAX));
+            assert_eq!(interval.to_string().parse::<Interval<i64>>().unwrap(), interval);
+        });
+    }
+
+    #[test]
+    fn test_set_laws() {
+        fuzz::check(0..1000, |rng| {
+            let mut interval = || Interval::new(rng.range(-10..=10), rng.range(-10..=10));
+            let (a, b) = (interval(), interval());
+            let values = |intervals: &[Interval<i32>]| {
+                (-12..=12).filter(|&v| intervals.iter().any(|i| i.includes(v))).collect::<Vec<_>>()
+            };
+            let (va, vb) = (values(&[a]), values(&[b]));
+            assert_eq!(a.len(), va.len() as u128);
+            assert_eq!(values(&[a.intersection(&b)]), va.iter().filter(|v| vb.contains(v)).copied().collect::<Vec<_>>());
+            assert_eq!(values(&a.difference(&b)), va.iter().filter(|v| !vb.contains(v)).copied().collect::<Vec<_>>());
+            let merged = Interval::merge([a, b]);
+            assert_eq!(values(&merged), values(&[a, b]));
+            match a.union(&b) {
+                Some(union) => assert_eq!(Interval::merge([union]), merged),
+                None => assert_eq!(merged.len(), 2),
+            }
+        });
+    }
+}
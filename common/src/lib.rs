@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::process;
+
+pub use error::{ParseError, Source};
+pub use input::InputSource;
+pub use rng::Rng;
+
+pub mod bench;
+mod error;
+pub mod fuzz;
+pub mod image;
+mod input;
+pub mod interval;
+pub mod log;
+mod rng;
+pub mod search;
+
+/// An example from a puzzle's text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    /// The answers the puzzle gives for parts 1 and 2, if it gives one for this example.
+    pub answers: [Option<&'static str>; 2],
+}
+
+/// A day's puzzle: the input is parsed once and both parts are solved from the parsed form.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+    type Input: 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// The examples of the puzzle text, which `aoc run --example` solves.
+    const EXAMPLES: &'static [Example];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// A random puzzle input that [`Solution::parse`] accepts, growing with `size`; what `size` counts
+    /// (lines, valves, numbers...) is up to the day.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Object-safe view of a [`Solution`], so days with different input and answer types can be
+/// stored side by side, e.g. in the runner's list of days.
+pub trait AnySolution: Sync {
+    fn day(&self) -> u8;
+
+    fn examples(&self) -> &'static [Example];
+
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves `part` (1 or 2) of an input returned by [`AnySolution::parse_any`].
+    fn solve_any(&self, input: &dyn Any, part: u8) -> Option<String>;
+
+    fn generate_any(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+impl<S: Solution + Sync> AnySolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_any(&self, input: &dyn Any, part: u8) -> Option<String> {
+        let input = input.downcast_ref::<S::Input>().expect("input parsed by another solution");
+        match part {
+            1 => Some(S::part1(input).to_string()),
+            2 => Some(S::part2(input).to_string()),
+            _ => None,
+        }
+    }
+
+    fn generate_any(&self, rng: &mut Rng, size: usize) -> String {
+        S::generate(rng, size)
+    }
+}
+
+/// Formats an answer as `puzzle<part>:<answer>`, moving multi-line answers onto their own lines.
+pub fn format_answer(part: u8, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("puzzle{}:\n{}", part, answer)
+    } else {
+        format!("puzzle{}:{}", part, answer)
+    }
+}
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `puzzle<part>:<answer>`, see [`format_answer`].
+    Text,
+    /// One `{"day", "part", "answer", "elapsed_ns"}` object per line, the answer always as a string.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {:?}, expected text or json", s)),
+        }
+    }
+}
+
+/// Formats the answer to a part, solved in `elapsed`, as a line of output.
+pub fn format_record(format: Format, day: u8, part: u8, answer: &str, elapsed: Duration) -> String {
+    match format {
+        Format::Text => format_answer(part, answer),
+        Format::Json => format!(r#"{{"day":{},"part":{},"answer":{},"elapsed_ns":{}}}"#,
+                                day, part, serde_json::Value::from(answer), elapsed.as_nanos()),
+    }
+}
+
+/// The flags of a day binary that take a value, see [`log::take_verbosity`].
+pub const VALUED_FLAGS: &[&str] = &["--format", "--frames"];
+
+/// Parses the `[--format <text|json>] [--frames <dir>] [path|-]` arguments of a day binary.
+fn parse_args(args: &[String]) -> Result<(Format, Option<&str>, Option<&str>), String> {
+    let (mut format, mut path, mut frames) = (Format::Text, None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().ok_or("missing value for --format")?.parse()?,
+            "--frames" => frames = Some(args.next().ok_or("missing value for --frames")?.as_str()),
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    Ok((format, path, frames))
+}
+
+/// Entry point shared by the day binaries: solves both parts of the input and prints them.
+///
+/// The input is the path given as an argument (`-` for stdin), or else resolved as in [`InputSource::resolve`].
+/// `--format json` prints the answers as JSON records, `--frames <dir>` writes pictures of the simulations
+/// into `dir`, and `-v` to `-vvv` log the solver's progress.
+pub fn main<S: Solution>() {
+    main_with::<S>(&log::init(false, VALUED_FLAGS));
+}
+
+/// [`main`], for a binary that took the verbosity flags out of its arguments `args` itself to look at them first.
+pub fn main_with<S: Solution>(args: &[String]) {
+    let (format, path, frames) = parse_args(args).unwrap_or_else(|e| {
+        eprintln!("error: {}\nusage: [-v|-vv|-vvv] [--format <text|json>] [--frames <dir>] [path|-]", e);
+        process::exit(1);
+    });
+    if let Some(dir) = frames {
+        image::record_to(Path::new(dir)).unwrap_or_else(|e| {
+            eprintln!("error: {}: {}", dir, e);
+            process::exit(1);
+        });
+    }
+    let source = InputSource::resolve(S::DAY, path);
+    let input = source.read().unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    let input = S::parse(&input).unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", source, e);
+        process::exit(1);
+    });
+    let start = Instant::now();
+    let answer = S::part1(&input).to_string();
+    println!("{}", format_record(format, S::DAY, 1, &answer, start.elapsed()));
+    let start = Instant::now();
+    let answer = S::part2(&input).to_string();
+    println!("{}", format_record(format, S::DAY, 2, &answer, start.elapsed()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 1;
+        type Input = Vec<usize>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        const EXAMPLES: &'static [Example] = &[Example { name: "example", input: "ab\ncde", answers: [Some("5"), None] }];
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(|l| l.len()).collect())
+        }
+
+        fn part1(input: &Self::Input) -> usize {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> String {
+            input.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\n")
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| {
+                let len = rng.range(0..=3);
+                rng.word("ab", len) + "\n"
+            }).collect()
+        }
+    }
+
+    #[test]
+    fn test_any_solution() {
+        let solution: &dyn AnySolution = &Lengths;
+        assert_eq!(solution.day(), 1);
+        assert_eq!(solution.examples()[0].answers, [Some("5"), None]);
+        let input = solution.parse_any("ab\ncde").unwrap();
+        assert_eq!(solution.solve_any(input.as_ref(), 1), Some("5".to_string()));
+        assert_eq!(solution.solve_any(input.as_ref(), 2), Some("2\n3".to_string()));
+        assert_eq!(solution.solve_any(input.as_ref(), 3), None);
+        let generated = solution.generate_any(&mut Rng::new(1), 5);
+        assert_eq!(generated, solution.generate_any(&mut Rng::new(1), 5));
+        assert_eq!(generated.lines().count(), 5);
+    }
+
+    #[test]
+    fn test_format_answer() {
+        assert_eq!(format_answer(1, "42"), "puzzle1:42");
+        assert_eq!(format_answer(2, "#.\n.#"), "puzzle2:\n#.\n.#");
+    }
+
+    #[test]
+    fn test_format_record() {
+        let elapsed = Duration::from_micros(5);
+        assert_eq!(format_record(Format::Text, 10, 1, "13760", elapsed), "puzzle1:13760");
+        assert_eq!(format_record(Format::Json, 10, 2, "#.\n.#", elapsed),
+                   r##"{"day":10,"part":2,"answer":"#.\n.#","elapsed_ns":5000}"##);
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+        assert_eq!(parse_args(&args("")), Ok((Format::Text, None, None)));
+        assert_eq!(parse_args(&args("--format json -")), Ok((Format::Json, Some("-"), None)));
+        assert_eq!(parse_args(&args("in.txt --frames out")), Ok((Format::Text, Some("in.txt"), Some("out"))));
+        assert!(parse_args(&args("a.txt b.txt")).is_err());
+        assert!(parse_args(&args("--format")).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use std::env;
+use std::fmt::{self, Arguments};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much the solvers tell about their progress on stderr, each level including the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [Level; 4] = [Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+/// Logs messages up to `level` from now on, for every thread.
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Takes the verbosity flags out of `args`: every `v` of `-v`, `-vv` or `-vvv` raises the level above
+/// [`Level::Warn`] by one, up to [`Level::Trace`].
+///
+/// Only flags that come before the positional arguments are taken, after the first one if it names a
+/// `subcommand`, and none after `--`, which is dropped. The value after a flag in `valued` is left alone,
+/// even if it looks like `-v`.
+pub fn take_verbosity(args: &[String], subcommand: bool, valued: &[&str]) -> (Level, Vec<String>) {
+    let is_verbosity = |arg: &String| arg.len() > 1 && arg.starts_with('-') && arg[1..].bytes().all(|b| b == b'v');
+    let (mut count, mut rest, mut subcommand) = (0, vec![], subcommand);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            rest.extend(args.by_ref().cloned());
+        } else if is_verbosity(arg) {
+            count += arg.len() - 1;
+        } else if valued.contains(&arg.as_str()) {
+            rest.push(arg.clone());
+            rest.extend(args.next().cloned());
+        } else if !arg.starts_with('-') || arg == "-" {
+            rest.push(arg.clone());
+            if !subcommand {
+                rest.extend(args.by_ref().cloned());
+            }
+            subcommand = false;
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    (LEVELS[count.min(LEVELS.len() - 1)], rest)
+}
+
+/// Takes the verbosity flags out of the arguments of the program, as [`take_verbosity`] does, and logs
+/// up to the level they ask for, returning the other arguments.
+pub fn init(subcommand: bool, valued: &[&str]) -> Vec<String> {
+    let (level, args) = take_verbosity(&env::args().skip(1).collect::<Vec<_>>(), subcommand, valued);
+    set_max_level(level);
+    args
+}
+
+/// A log line, with the module that wrote it, e.g. `debug day17: cycle of 1725 rocks`.
+pub fn format(level: Level, target: &str, args: Arguments) -> String {
+    format!("{} {}: {}", level, target, args)
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: Arguments) {
+    eprintln!("{}", format(level, target, args));
+}
+
+/// Logs a message at a [`Level`]; the arguments are only evaluated when the level is enabled, so they
+/// can be expensive to format.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::log::*;
+
+    #[test]
+    fn test_take_verbosity() {
+        let args = |s: &str| s.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+        let take = |s: &str| take_verbosity(&args(s), true, &["--day", "--input"]);
+        assert_eq!(take("run --day 7"), (Level::Warn, args("run --day 7")));
+        assert_eq!(take("-v run --day 7"), (Level::Info, args("run --day 7")));
+        assert_eq!(take("-vv run -v"), (Level::Trace, args("run")));
+        assert_eq!(take("-vvvvv run --input -"), (Level::Trace, args("run --input -")));
+        // values of other flags, positional arguments and what follows `--` are kept
+        assert_eq!(take("run --input -v -v"), (Level::Info, args("run --input -v")));
+        assert_eq!(take("-v run -- -vv"), (Level::Info, args("run -vv")));
+        assert_eq!(take_verbosity(&args("-v in.txt -v"), false, &[]), (Level::Info, args("in.txt -v")));
+        assert_eq!(take_verbosity(&args("- -vv"), false, &[]), (Level::Warn, args("- -vv")));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(Level::Debug, "day17", format_args!("cycle of {} rocks", 35)), "debug day17: cycle of 35 rocks");
+        assert!(enabled(Level::Warn));
+    }
+}
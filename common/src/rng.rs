@@ -0,0 +1,82 @@
+use std::ops::RangeInclusive;
+
+/// A seeded SplitMix64 generator: small, and the same numbers for a seed on every platform, so a
+/// generated input can always be reproduced from its seed and size.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let wide = |n: T| n.try_into().ok().expect("an integer fits in an i128");
+        let (start, end) = (wide(*range.start()), wide(*range.end()));
+        assert!(start <= end, "empty range");
+        let n = start + (u128::from(self.next_u64()) % (end - start + 1) as u128) as i128;
+        T::try_from(n).ok().unwrap()
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+
+    /// A word of `len` random letters from `letters`.
+    pub fn word(&mut self, letters: &str, len: usize) -> String {
+        let letters = letters.chars().collect::<Vec<_>>();
+        (0..len).map(|_| *self.choose(&letters)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rng::*;
+
+    #[test]
+    fn test_rng() {
+        // the first outputs of the SplitMix64 reference implementation for seed 0
+        let mut rng = Rng::new(0);
+        assert_eq!([rng.next_u64(), rng.next_u64()], [0xe220a8397b1dcdaf, 0x6e789e6aa1b965f4]);
+
+        let mut rng = Rng::new(7);
+        let rolls = (0..1000).map(|_| rng.range(-3..=3i8)).collect::<Vec<_>>();
+        assert!((-3..=3).all(|n| rolls.contains(&n)) && rolls.iter().all(|n| (-3..=3).contains(n)));
+        assert_eq!(Rng::new(7).range(0..=u64::MAX), Rng::new(7).next_u64());
+        assert_eq!(Rng::new(1).word("ab", 8), Rng::new(1).word("ab", 8));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut items = (0..20).collect::<Vec<_>>();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
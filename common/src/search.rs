@@ -0,0 +1,208 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cost of a path and its states, from the start to the goal, both included.
+pub type Path<S, C> = (C, Vec<S>);
+
+/// Every state reached so far, with the state it was reached from and the cost of getting there.
+struct Tree<S, C> {
+    nodes: Vec<(S, Option<usize>, C)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C> Tree<S, C> {
+    fn new() -> Self {
+        Tree { nodes: vec![], index: HashMap::new() }
+    }
+
+    /// Adds a state that was not reached yet, returning its index.
+    fn insert(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize> {
+        if self.index.contains_key(&state) {
+            return None;
+        }
+        self.index.insert(state.clone(), self.nodes.len());
+        self.nodes.push((state, parent, cost));
+        Some(self.nodes.len() - 1)
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            path.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search for the fewest steps from `start` to a state satisfying `success`.
+pub fn bfs<S, I>(start: S, successors: impl FnMut(&S) -> I, success: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_multi([start], successors, success)
+}
+
+/// Like [`bfs`], but starting from all of `starts` at once, so the path begins at the closest one.
+pub fn bfs_multi<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut tree = Tree::new();
+    let mut queue = starts.into_iter().filter_map(|start| tree.insert(start, None, 0)).collect::<VecDeque<_>>();
+    while let Some(i) = queue.pop_front() {
+        let (ref state, _, steps) = tree.nodes[i];
+        if success(state) {
+            return Some((steps, tree.path(i)));
+        }
+        for next in successors(state) {
+            queue.extend(tree.insert(next, Some(i), steps + 1));
+        }
+    }
+    None
+}
+
+/// Every state at most `max_steps` away from `start`, with its distance in steps.
+pub fn reachable<S, I>(start: S, max_steps: usize, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut steps = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, n)) = queue.pop_front() {
+        if n == max_steps {
+            continue;
+        }
+        for next in successors(&state) {
+            // one lookup per state, as the search may reach millions of them
+            if let Entry::Vacant(entry) = steps.entry(next.clone()) {
+                entry.insert(n + 1);
+                queue.push_back((next, n + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// Dijkstra's search for the cheapest path from `start` to a state satisfying `success`,
+/// `successors` giving every next state along with the cost of the step.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I, success: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi([start], successors, |_| C::default(), success)
+}
+
+/// Like [`dijkstra`], but starting from all of `starts` at once.
+pub fn dijkstra_multi<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi(starts, successors, |_| C::default(), success)
+}
+
+/// A* search: [`dijkstra`] guided by `heuristic`, which must never overestimate the cost left to a goal.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_multi([start], successors, heuristic, success)
+}
+
+fn astar_multi<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut tree = Tree::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        heap.extend(tree.insert(start, None, C::default()).map(|i| Reverse((estimate, C::default(), i))));
+    }
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let (ref state, _, best) = tree.nodes[i];
+        if cost > best {
+            continue; // a cheaper way here was found after this one was queued
+        }
+        if success(state) {
+            return Some((cost, tree.path(i)));
+        }
+        for (next, step) in successors(state) {
+            let cost = cost + step;
+            let j = match tree.index.get(&next) {
+                Some(&j) if tree.nodes[j].2 <= cost => continue,
+                Some(&j) => {
+                    tree.nodes[j].1 = Some(i);
+                    tree.nodes[j].2 = cost;
+                    j
+                }
+                None => tree.insert(next, Some(i), cost).unwrap(),
+            };
+            heap.push(Reverse((cost + heuristic(&tree.nodes[j].0), cost, j)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    /// The numbers from -20 to 20, each leading to its neighbours and to its double at a cost of 1.
+    fn line(&n: &i32) -> Vec<(i32, u32)> {
+        [n - 1, n + 1, n * 2].into_iter().filter(|n| (-20..=20).contains(n)).map(|n| (n, 1)).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let next = |n: &i32| line(n).into_iter().map(|(n, _)| n);
+        assert_eq!(bfs(1, next, |&n| n == 7), Some((4, vec![1, 2, 3, 6, 7])));
+        assert_eq!(bfs_multi([10, -3], next, |&n| n == -6), Some((1, vec![-3, -6])));
+        assert_eq!(bfs(1, next, |&n| n == 21), None);
+        let near = reachable(0, 2, next);
+        assert_eq!((near.len(), near[&-2], near.get(&3)), (5, 2, None));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going up by one is free, so it is cheaper than doubling
+        let next = |&n: &i32| line(&n).into_iter().map(move |(m, c)| (m, if m == n + 1 { 0 } else { c }));
+        assert_eq!(dijkstra(1, next, |&n| n == 5), Some((0, vec![1, 2, 3, 4, 5])));
+        assert_eq!(dijkstra_multi([30, 8], next, |&n| n == 6), Some((2, vec![8, 7, 6])));
+        let (cost, path) = astar(3, line, |&n| (20 - n).unsigned_abs().min(1), |&n| n == 20).unwrap();
+        assert_eq!((cost, path.first(), path.last()), (4, Some(&3), Some(&20)));
+    }
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use common::bench::{format_duration, measure, Stats};
+use common::{InputSource, Rng};
+use day01::*;
+
+type Puzzle = fn(&[u64]) -> u128;
+
+fn report(name: &str, stats: Stats) {
+    println!("{:<24}  mean {:>9}  stddev {:>9}  min {:>9}",
+             name, format_duration(stats.mean), format_duration(stats.stddev), format_duration(stats.min));
+}
+
+fn main() {
+    let input = parse(&InputSource::resolve(1, None).read().unwrap()).unwrap();
+    let benches: [(&str, Puzzle); 3] = [
+        ("puzzle1", |calories| puzzle1(calories).into()),
+        ("puzzle2", puzzle2),
+        ("puzzle2_heap", puzzle2_heap),
+    ];
+    for (name, f) in benches {
+        report(name, measure(1000, Duration::from_secs(3), || f(&input)));
+    }
+    // the top-k strategies on generated inventories of growing size, for a few k
+    for elves in [1_000, 100_000] {
+        let calories = parse(&generate(&mut Rng::new(1), elves)).unwrap();
+        for k in [1, 3, 100, elves / 2] {
+            for strategy in STRATEGIES {
+                let name = format!("{} n={} k={}", strategy, elves, k);
+                report(&name, measure(1000, Duration::from_secs(1), || top_k(&calories, k, strategy)));
+            }
+        }
+    }
+}
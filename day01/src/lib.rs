@@ -0,0 +1,132 @@
+use common::{Example, ParseError, Rng, Solution, Source};
+
+pub use stream::{elves, top_elves, total, Elf, Elves, StreamError};
+pub use top::{top_k, Strategy, STRATEGIES};
+
+pub mod report;
+pub mod stream;
+pub mod top;
+
+/// The calories every elf carries, read as [`elves`] streams them; there must be an elf.
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    let calories = elves(input.as_bytes()).map(|elf| match elf {
+        Ok(elf) => Ok(elf.calories),
+        Err(StreamError::Parse(e)) => Err(e),
+        Err(StreamError::Io(e)) => unreachable!("reading a string failed: {}", e),
+    }).collect::<Result<Vec<_>, _>>()?;
+    if calories.is_empty() {
+        return Err(Source::new(input).missing(input, "a calorie count"));
+    }
+    Ok(calories)
+}
+
+/// The sum of `calories`, as a `u128`, since the totals of a few elves can add up past 2^64.
+fn sum(calories: impl IntoIterator<Item = u64>) -> u128 {
+    calories.into_iter().map(u128::from).sum()
+}
+
+pub fn puzzle1(calories: &[u64]) -> u64 {
+    calories.iter().copied().max().unwrap()
+}
+
+pub fn puzzle2(calories: &[u64]) -> u128 {
+    sum(top_k(calories, 3, Strategy::Sort))
+}
+
+pub fn puzzle2_heap(calories: &[u64]) -> u128 {
+    sum(top_k(calories, 3, Strategy::Heap))
+}
+
+/// `size` elves carrying 1 to 10 snacks each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| {
+        (0..rng.range(1..=10)).map(|_| format!("{}\n", rng.range(1000..=70000))).collect::<String>()
+    }).collect::<Vec<_>>().join("\n")
+}
+
+const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("24000"), Some("45000")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u128 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 24000);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 45000);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("1000\n2000\n\n30x0").unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (4, 1, "30x0"));
+    }
+
+    #[test]
+    fn test_puzzle2_heap() {
+        assert_eq!(puzzle2_heap(&parse(INPUT).unwrap()), 45000);
+    }
+
+    #[test]
+    fn test_calorie_overflow() {
+        // totals may pass 2^32, not 2^64
+        assert_eq!(parse("3000000000\n2000000000"), Ok(vec![5_000_000_000]));
+        assert!(parse("18446744073709551615\n1").is_err());
+        // the top three may not
+        let calories = parse(&format!("{}\n\n{}", u64::MAX, u64::MAX)).unwrap();
+        assert_eq!(puzzle2(&calories), 2 * u128::from(u64::MAX));
+        assert_eq!(puzzle2_heap(&calories), 2 * u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_no_elves() {
+        assert_eq!(parse("").unwrap_err().to_string(), "line 1, column 1: expected a calorie count, found end of line");
+        assert_eq!(parse("5\n\n7\n\n"), Ok(vec![5, 7]));
+    }
+}
@@ -1,108 +1,63 @@
-#![feature(test)]
-extern crate test;
+use std::process;
 
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use std::fs;
+use common::InputSource;
+use day01::{elves, report, top_elves, total, Day01};
 
-fn puzzle1(input: &str) -> u32 {
-    input
-        .split("\n\n")
-        .map(|group| group.lines().map(|s| s.parse::<u32>().unwrap()).sum::<u32>())
-        .max()
-        .unwrap()
-}
+const USAGE: &str = "usage: [-v|-vv|-vvv] [--format <text|json>] [path|-]
+       --stream [path|-]
+       --report [--top <k>] [path|-]";
 
-fn puzzle2(input: &str) -> u32 {
-    let mut calories: Vec<_> = input
-        .split("\n\n")
-        .map(|group| group.lines().map(|s| s.parse::<u32>().unwrap()).sum::<u32>())
-        .collect();
-    calories.sort_by(|a, b| b.cmp(a));
-    calories.into_iter().take(3).sum()
+/// `--stream` reads the inventory a line at a time instead of all at once, so that it can be larger than
+/// memory, and also says which elves carry the most. `--report` prints statistics of the inventory.
+fn main() {
+    let args = common::log::init(false, &[common::VALUED_FLAGS, &["--top"]].concat());
+    let result = match args.first().map(String::as_str) {
+        Some("--stream") => parse_args(&args[1..]).and_then(|(top, path)| match top {
+            Some(_) => Err("--top is only for --report".to_string()),
+            None => stream(path),
+        }),
+        Some("--report") => parse_args(&args[1..]).and_then(|(top, path)| print_report(top.unwrap_or(3), path)),
+        _ => {
+            common::main_with::<Day01>(&args);
+            return;
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
 
-fn puzzle2_heap(input: &str) -> u32 {
-    let mut heap = BinaryHeap::new();
-    input
-        .split("\n\n")
-        .map(|group| group.lines().map(|s| s.parse::<u32>().unwrap()).sum::<u32>())
-        .for_each(|caloric| {
-            if heap.len() < 3 {
-                heap.push(Reverse(caloric));
-            } else if heap.peek().unwrap().0 < caloric {
-                heap.pop();
-                heap.push(Reverse(caloric));
+/// Parses the `[--top <k>] [path|-]` arguments after `--stream` or `--report`.
+fn parse_args(args: &[String]) -> Result<(Option<usize>, Option<&str>), String> {
+    let (mut top, mut path) = (None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => {
+                let value = args.next().ok_or(format!("missing value for --top\n{}", USAGE))?;
+                top = Some(value.parse().map_err(|_| format!("--top expects a number, found {:?}", value))?);
             }
-        });
-    (0..3).flat_map(|_| heap.pop().map(|r| r.0)).sum::<u32>()
-}
-
-#[cfg(test)]
-mod tests {
-    use std::fs;
-    use test::Bencher;
-
-    use crate::*;
-
-    const INPUT: &'static str = "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000";
-
-    #[test]
-    fn test_puzzle1() {
-        assert_eq!(puzzle1(INPUT), 24000);
-    }
-
-    #[test]
-    fn test_puzzle2() {
-        assert_eq!(puzzle2(INPUT), 45000);
-    }
-
-    #[test]
-    fn test_puzzle2_heap() {
-        assert_eq!(puzzle2(INPUT), 45000);
-    }
-
-    #[bench]
-    fn bench_puzzle1(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        b.iter(|| {
-            puzzle1(&input)
-        });
-    }
-
-    #[bench]
-    fn bench_puzzle2(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        b.iter(|| {
-            puzzle2(&input)
-        });
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {}\n{}", arg, USAGE)),
+        }
     }
+    Ok((top, path))
+}
 
-    #[bench]
-    fn bench_puzzle2_heap(b: &mut Bencher) {
-        let input = fs::read_to_string("input.txt").unwrap();
-        b.iter(|| {
-            puzzle2_heap(&input)
-        });
-    }
+fn stream(path: Option<&str>) -> Result<(), String> {
+    let source = InputSource::resolve(1, path);
+    let top = top_elves(source.open()?, 3).map_err(|e| format!("{}: {}", source, e))?;
+    let elf = top.first().ok_or(format!("{}: no elves", source))?;
+    println!("{}", common::format_answer(1, &format!("{}, carried by elf {}", elf.calories, elf.index)));
+    let indices = top.iter().map(|elf| elf.index.to_string()).collect::<Vec<_>>();
+    println!("{}", common::format_answer(2, &format!("{}, carried by elves {}", total(&top), indices.join(", "))));
+    Ok(())
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    println!("puzzle1:{:?}", puzzle1(&input));
-    println!("puzzle2:{:?}", puzzle2(&input));
-    println!("puzzle2_heap:{:?}", puzzle2_heap(&input));
+fn print_report(top: usize, path: Option<&str>) -> Result<(), String> {
+    let source = InputSource::resolve(1, path);
+    let elves = elves(source.open()?).collect::<Result<Vec<_>, _>>().map_err(|e| format!("{}: {}", source, e))?;
+    println!("{}", report::report(&elves, top));
+    Ok(())
 }
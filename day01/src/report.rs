@@ -0,0 +1,129 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::Elf;
+
+/// The percentiles the report gives, besides the median.
+const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+
+/// The number of equally wide ranges of totals the histogram counts the elves in.
+const BINS: u64 = 10;
+
+/// The length of the histogram's longest bar.
+const BAR: usize = 40;
+
+/// The total below which `p` percent of `sorted` lie, by the nearest-rank method.
+pub fn percentile(sorted: &[u64], p: u32) -> u64 {
+    let rank = (sorted.len() * p as usize).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+pub fn median(sorted: &[u64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] as f64 + sorted[mid] as f64) / 2.0
+    } else {
+        sorted[mid] as f64
+    }
+}
+
+/// How many of `sorted` fall in each of [`BINS`] equally wide ranges from the smallest to the largest, with
+/// where each range starts.
+pub fn histogram(sorted: &[u64]) -> Vec<(u64, usize)> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = ((max - min) / BINS + 1).max(1);
+    let mut bins = (0..BINS).map(|i| (min.saturating_add(i * width), 0)).collect::<Vec<_>>();
+    for &calories in sorted {
+        bins[((calories - min) / width) as usize].1 += 1;
+    }
+    bins
+}
+
+/// Every elf's items and calories, with the `k` carrying the most ranked, followed by statistics of the
+/// totals and a histogram of them.
+pub fn report(elves: &[Elf], k: usize) -> String {
+    if elves.is_empty() {
+        return "no elves".to_string();
+    }
+    let mut ranked = elves.iter().collect::<Vec<_>>();
+    ranked.sort_by_key(|elf| (Reverse(elf.calories), elf.index));
+    ranked.truncate(k);
+    let ranks = ranked.iter().enumerate().map(|(i, elf)| (elf.index, i + 1)).collect::<HashMap<_, _>>();
+
+    let mut s = format!("{:>6}  {:>5}  {:>11}  {:>4}", "elf", "items", "calories", "rank");
+    for elf in elves {
+        let rank = ranks.get(&elf.index).map_or(String::new(), |rank| rank.to_string());
+        let _ = write!(s, "\n{:>6}  {:>5}  {:>11}  {:>4}", elf.index, elf.items, elf.calories, rank);
+    }
+
+    let mut sorted = elves.iter().map(|elf| elf.calories).collect::<Vec<_>>();
+    sorted.sort_unstable();
+    let items = elves.iter().map(|elf| elf.items).sum::<usize>();
+    let calories = sorted.iter().map(|&c| u128::from(c)).sum::<u128>();
+    let _ = write!(s, "\n\n{} elves carrying {} items, {} calories in all", elves.len(), items, calories);
+    let _ = write!(s, "\nmean {:.1}, median {:.1}", calories as f64 / elves.len() as f64, median(&sorted));
+    let percentiles = PERCENTILES.iter().map(|&p| format!("p{} {}", p, percentile(&sorted, p))).collect::<Vec<_>>();
+    let _ = write!(s, "\npercentiles: {}", percentiles.join(", "));
+    let top = ranked.iter().map(|elf| elf.index.to_string()).collect::<Vec<_>>();
+    let top_calories = ranked.iter().map(|elf| u128::from(elf.calories)).sum::<u128>();
+    let _ = write!(s, "\ntop {}: elves {}, {} calories", ranked.len(), top.join(", "), top_calories);
+
+    let bins = histogram(&sorted);
+    let most = bins.iter().map(|&(_, count)| count).max().unwrap();
+    let width = bins.get(1).map_or(1, |&(start, _)| start - bins[0].0);
+    s += "\nhistogram of the totals:";
+    for (start, count) in bins {
+        let bar = "#".repeat((count * BAR).div_ceil(most));
+        let _ = write!(s, "\n{:>11} - {:<11} {:>6} {}", start, start.saturating_add(width - 1), count, bar);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+    use crate::{elves, INPUT};
+
+    #[test]
+    fn test_statistics() {
+        let sorted = [4000, 6000, 10000, 11000, 24000];
+        assert_eq!(PERCENTILES.map(|p| percentile(&sorted, p)), [4000, 6000, 10000, 11000, 24000, 24000]);
+        assert_eq!(percentile(&[7], 10), 7);
+        assert_eq!(median(&sorted), 10000.0);
+        assert_eq!(median(&sorted[1..]), 10500.0);
+        let bins = histogram(&sorted);
+        assert_eq!(bins[0], (4000, 2));
+        assert_eq!(bins[3], (10003, 1));
+        assert_eq!(bins.iter().map(|&(_, count)| count).sum::<usize>(), 5);
+        assert_eq!(histogram(&[3, 3]), (0..BINS).map(|i| (3 + i, if i == 0 { 2 } else { 0 })).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_report() {
+        let elves = elves(INPUT.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        let report = report(&elves, 3);
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "   elf  items     calories  rank");
+        assert_eq!(lines[1], "     0      3         6000      ");
+        assert_eq!(lines[4], "     3      3        24000     1");
+        assert_eq!(lines[7], "5 elves carrying 10 items, 55000 calories in all");
+        assert_eq!(lines[8], "mean 11000.0, median 10000.0");
+        assert_eq!(lines[9], "percentiles: p10 4000, p25 6000, p50 10000, p75 11000, p90 24000, p99 24000");
+        assert_eq!(lines[10], "top 3: elves 3, 2, 4, 45000 calories");
+        assert_eq!(lines[12], "       4000 - 6000             2 ########################################");
+        assert_eq!(lines.len(), 12 + BINS as usize);
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        // the blank line ends the last elf rather than starting an empty one
+        let elves = elves("5\n\n7\n\n".as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        let report = report(&elves, 3);
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(&lines[1..3], ["     0      1            5     2", "     1      1            7     1"]);
+        assert_eq!(lines[4], "2 elves carrying 2 items, 12 calories in all");
+        assert_eq!(lines[5], "mean 6.0, median 6.0");
+        assert_eq!(lines[7], "top 2: elves 1, 0, 12 calories");
+    }
+}
@@ -0,0 +1,161 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Lines, Read};
+
+use common::{ParseError, Source};
+
+/// The calories an elf carries in how many items, and where the elf comes in the inventory, counting from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Elf {
+    pub index: usize,
+    pub items: usize,
+    pub calories: u64,
+}
+
+/// Why an inventory could not be streamed: the reader failed, or what it read is malformed.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// The elves of an inventory, read a line at a time: a blank line ends an elf, and so does the end of the
+/// input after an elf's items, so a trailing blank line adds no elf. Only the line being read is held in memory.
+pub struct Elves<R> {
+    lines: Lines<R>,
+    /// The number of the last line read, from 1.
+    line: usize,
+    index: usize,
+    done: bool,
+}
+
+/// Streams the elves of the inventory `reader` holds, in the order they come in.
+pub fn elves<R: Read>(reader: R) -> Elves<BufReader<R>> {
+    Elves { lines: BufReader::new(reader).lines(), line: 0, index: 0, done: false }
+}
+
+impl<R: BufRead> Elves<R> {
+    /// The next elf, unless the input ends before any line of one.
+    fn next_elf(&mut self) -> Result<Option<Elf>, StreamError> {
+        let (mut items, mut calories) = (0, 0u64);
+        for line in self.lines.by_ref() {
+            let line = line?;
+            self.line += 1;
+            if line.is_empty() {
+                return Ok(Some(Elf { index: self.index, items, calories }));
+            }
+            items += 1;
+            // the position within the line comes from a source of just the line
+            let src = Source::new(&line);
+            let at_line = |e: ParseError| ParseError { line: self.line, ..e };
+            let count = src.parse::<u64>(&line, "a calorie count").map_err(at_line)?;
+            calories = calories.checked_add(count)
+                .ok_or_else(|| at_line(src.error(&line, "a calorie count keeping the elf's total below 2^64")))?;
+        }
+        self.done = true;
+        // a blank line already ended the elf before, if there were no items since
+        Ok((items > 0).then_some(Elf { index: self.index, items, calories }))
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let elf = self.next_elf();
+        self.index += 1;
+        if elf.is_err() {
+            self.done = true;
+        }
+        elf.transpose()
+    }
+}
+
+/// The `n` elves carrying the most calories, most first, reading `reader` in one pass and keeping only
+/// those `n` elves in memory. Of elves carrying as much, the first ones in the inventory win.
+pub fn top_elves<R: Read>(reader: R, n: usize) -> Result<Vec<Elf>, StreamError> {
+    // not preallocated, as `n` may be far more than the elves of the inventory
+    let mut heap = BinaryHeap::new();
+    for elf in elves(reader) {
+        let elf = elf?;
+        heap.push(Reverse((elf.calories, Reverse(elf.index), elf.items)));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    let top = heap.into_sorted_vec();
+    Ok(top.into_iter().map(|Reverse((calories, Reverse(index), items))| Elf { index, items, calories }).collect())
+}
+
+/// The calories `elves` carry between them, as a `u128` that even the totals of many elves cannot overflow.
+pub fn total(elves: &[Elf]) -> u128 {
+    elves.iter().map(|elf| u128::from(elf.calories)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stream::*;
+    use crate::INPUT;
+
+    #[test]
+    fn test_elves() {
+        let elves = elves(INPUT.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(elves.iter().map(|elf| elf.calories).collect::<Vec<_>>(), [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(elves[3], Elf { index: 3, items: 3, calories: 24000 });
+        let top = top_elves(INPUT.as_bytes(), 3).unwrap();
+        assert_eq!(top.iter().map(|elf| elf.index).collect::<Vec<_>>(), [3, 2, 4]);
+        assert_eq!(total(&top), 45000);
+        assert_eq!(top_elves("5\n\n5\n\n1".as_bytes(), 1).unwrap(), [Elf { index: 0, items: 1, calories: 5 }]);
+        assert_eq!(top_elves(INPUT.as_bytes(), usize::MAX).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_end_of_input() {
+        let calories = |input: &str| elves(input.as_bytes()).map(|elf| elf.unwrap().calories).collect::<Vec<_>>();
+        assert_eq!(calories("5\n\n7\n\n"), [5, 7]);
+        assert_eq!(calories("5\n\n7\n"), [5, 7]);
+        assert_eq!(calories("5\n\n7"), [5, 7]);
+        assert!(calories("").is_empty());
+        assert!(top_elves("".as_bytes(), 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = format!("1\n\n{}\n{}\n", u64::MAX - 1, 1);
+        assert_eq!(top_elves(input.as_bytes(), 1).unwrap(), [Elf { index: 1, items: 2, calories: u64::MAX }]);
+        let input = format!("{}\n{}\n\n{}", u64::MAX, 1, 2);
+        let mut elves = elves(input.as_bytes());
+        let Some(Err(StreamError::Parse(e))) = elves.next() else { panic!("the overflow is not reported") };
+        assert_eq!((e.line, e.column, e.found.as_str()), (2, 1, "1"));
+        assert!(elves.next().is_none());
+        assert_eq!(total(&[Elf { index: 0, items: 1, calories: u64::MAX }; 2]), 2 * u128::from(u64::MAX));
+    }
+}
@@ -0,0 +1,92 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+/// How [`top_k`] picks the largest totals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Sorts every total, O(n log n).
+    Sort,
+    /// Keeps the `k` largest so far in a min-heap, O(n log k) with only `k` totals held.
+    Heap,
+    /// Partitions around the `k`th largest with `select_nth_unstable`, O(n) on average, then sorts the `k`.
+    Select,
+}
+
+pub const STRATEGIES: [Strategy; 3] = [Strategy::Sort, Strategy::Heap, Strategy::Select];
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Strategy::Sort => "sort",
+            Strategy::Heap => "heap",
+            Strategy::Select => "select",
+        })
+    }
+}
+
+/// The `k` largest of `calories`, largest first; all of them when there are no more than `k`.
+pub fn top_k(calories: &[u64], k: usize, strategy: Strategy) -> Vec<u64> {
+    match strategy {
+        Strategy::Sort => {
+            let mut calories = calories.to_vec();
+            calories.sort_unstable_by(|a, b| b.cmp(a));
+            calories.truncate(k);
+            calories
+        }
+        Strategy::Heap => {
+            let mut heap = BinaryHeap::with_capacity(k.min(calories.len()) + 1);
+            for &caloric in calories {
+                if heap.len() < k {
+                    heap.push(Reverse(caloric));
+                } else if heap.peek().is_some_and(|smallest| smallest.0 < caloric) {
+                    heap.pop();
+                    heap.push(Reverse(caloric));
+                }
+            }
+            heap.into_sorted_vec().into_iter().map(|r| r.0).collect()
+        }
+        Strategy::Select => {
+            let mut calories = calories.to_vec();
+            if k > 0 && k < calories.len() {
+                calories.select_nth_unstable_by(k - 1, |a, b| b.cmp(a));
+            }
+            calories.truncate(k);
+            calories.sort_unstable_by(|a, b| b.cmp(a));
+            calories
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::fuzz;
+
+    use crate::top::*;
+
+    #[test]
+    fn test_top_k() {
+        let calories = [6000, 4000, 11000, 24000, 10000];
+        for strategy in STRATEGIES {
+            assert_eq!(top_k(&calories, 3, strategy), [24000, 11000, 10000], "{}", strategy);
+            assert!(top_k(&calories, 0, strategy).is_empty(), "{}", strategy);
+            assert_eq!(top_k(&calories, 9, strategy), [24000, 11000, 10000, 6000, 4000], "{}", strategy);
+            assert_eq!(top_k(&calories, usize::MAX, strategy), [24000, 11000, 10000, 6000, 4000], "{}", strategy);
+            assert!(top_k(&[], 3, strategy).is_empty(), "{}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_strategies_agree() {
+        fuzz::check(0..500, |rng| {
+            let len = rng.range(0..=50);
+            // few distinct totals, so that there are ties around the kth largest
+            let calories = (0..len).map(|_| rng.range(0..=20) * 1000).collect::<Vec<u64>>();
+            let k = rng.range(0..=len + 2);
+            let expected = top_k(&calories, k, Strategy::Sort);
+            for strategy in STRATEGIES {
+                assert_eq!(top_k(&calories, k, strategy), expected, "{} of {:?} with k = {}", strategy, calories, k);
+            }
+        });
+    }
+}
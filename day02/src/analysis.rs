@@ -0,0 +1,218 @@
+use std::fmt::Write;
+
+use crate::{Game, Outcome, Round, OUTCOMES};
+
+/// A way of reading the second column of a guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decoding {
+    /// The shape to play for each of the game's letters for your shapes, in the order of its shapes.
+    Shapes(Vec<usize>),
+    /// How the round must end, as the game's outcome letters say.
+    Outcomes,
+}
+
+/// A round as a decoding plays it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub opponent: usize,
+    pub column: char,
+    pub you: usize,
+    pub outcome: Outcome,
+    pub score: u32,
+}
+
+/// The score of following a guide with a decoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reading {
+    pub decoding: Decoding,
+    /// `None` when some round's letter cannot be read this way.
+    pub score: Option<u64>,
+}
+
+/// The most shapes a game can have for [`readings`] to score and keep every decoding, 7! of them; this cap is
+/// what bounds the work and the memory of an analysis.
+pub const MAX_SHAPES: usize = 7;
+
+/// Every order of `0..n`, in lexicographic order, made one at a time.
+struct Permutations {
+    next: Option<Vec<usize>>,
+}
+
+fn permutations(n: usize) -> Permutations {
+    Permutations { next: Some((0..n).collect()) }
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        let order = self.next.take()?;
+        // the next order swaps the last element before a descending tail with the smallest larger one in
+        // the tail, then reverses the tail; there is none once the whole order is descending
+        if let Some(i) = order.windows(2).rposition(|w| w[0] < w[1]) {
+            let mut next = order.clone();
+            let j = i + next[i + 1..].partition_point(|&x| x > next[i]);
+            next.swap(i, j);
+            next[i + 1..].reverse();
+            self.next = Some(next);
+        }
+        Some(order)
+    }
+}
+
+/// Every way of reading the second column: each assignment of shapes to the letters of your shapes, the
+/// game's own first, and then as outcomes. There are n! + 1 of them for n shapes.
+pub fn decodings(game: &Game) -> impl Iterator<Item = Decoding> {
+    permutations(game.shapes().len()).map(Decoding::Shapes).chain([Decoding::Outcomes])
+}
+
+/// The round played as `decoding` reads it, if it can read the round's letter.
+pub fn play(game: &Game, round: &Round, decoding: &Decoding) -> Option<Play> {
+    let (opponent, column) = (round.opponent, round.column);
+    let you = match decoding {
+        Decoding::Shapes(shapes) => shapes[round.shape?],
+        Decoding::Outcomes => game.play_for(opponent, round.outcome?)?,
+    };
+    Some(Play { opponent, column, you, outcome: game.outcome(opponent, you), score: game.score(opponent, you) })
+}
+
+/// The shape scoring most against `opponent`.
+pub fn best(game: &Game, opponent: usize) -> usize {
+    (0..game.shapes().len()).max_by_key(|&you| game.score(opponent, you)).unwrap()
+}
+
+/// The most a guide against these opponent shapes can score, whatever its second column.
+pub fn max_score(game: &Game, guide: &[Round]) -> u64 {
+    guide.iter().map(|round| u64::from(game.score(round.opponent, best(game, round.opponent)))).sum()
+}
+
+/// The score of `guide` under every decoding, highest first: a guide is meant to make you do well, so the
+/// first reading is the most plausible one. Decodings scoring the same stay in the order of [`decodings`].
+/// Every reading is kept to be sorted and listed, so this fails for games of more than [`MAX_SHAPES`] shapes,
+/// which have too many decodings.
+pub fn readings(game: &Game, guide: &[Round]) -> Result<Vec<Reading>, String> {
+    let n = game.shapes().len();
+    if n > MAX_SHAPES {
+        return Err(format!("{} shapes have {}! decodings, too many to analyze above {} shapes", n, n, MAX_SHAPES));
+    }
+    let mut readings = decodings(game).map(|decoding| {
+        let score = guide.iter().map(|round| play(game, round, &decoding).map(|play| u64::from(play.score))).sum();
+        Reading { decoding, score }
+    }).collect::<Vec<_>>();
+    readings.sort_by_key(|reading| std::cmp::Reverse(reading.score));
+    Ok(readings)
+}
+
+/// `X=rock Y=paper Z=scissors` or `X=lose Y=draw Z=win`.
+pub fn describe(game: &Game, decoding: &Decoding) -> String {
+    let shapes = game.shapes();
+    match decoding {
+        Decoding::Shapes(order) => {
+            shapes.iter().zip(order).map(|(shape, &you)| format!("{}={}", shape.you, shapes[you].name))
+                .collect::<Vec<_>>().join(" ")
+        }
+        Decoding::Outcomes => {
+            OUTCOMES.iter().zip(["lose", "draw", "win"])
+                .map(|(&outcome, name)| format!("{}={}", game.letter_of(outcome), name))
+                .collect::<Vec<_>>().join(" ")
+        }
+    }
+}
+
+/// The scores of `guide` under every decoding next to the most it could score, and how the most plausible
+/// decoding plays every round next to the best play. Fails as [`readings`] does.
+pub fn analyze(game: &Game, guide: &[Round]) -> Result<String, String> {
+    let shapes = game.shapes();
+    let max = max_score(game, guide);
+    let readings = readings(game, guide)?;
+    let names = readings.iter().map(|reading| describe(game, &reading.decoding)).collect::<Vec<_>>();
+    let width = names.iter().map(String::len).max().unwrap_or(0).max("reading".len());
+    let mut s = format!("{} rounds, scoring at most {}", guide.len(), max);
+    let _ = write!(s, "\n{:<width$}  {:>7}  {:>6}", "reading", "score", "share", width = width);
+    for (reading, name) in readings.iter().zip(&names) {
+        let (score, share) = match reading.score {
+            Some(score) => (score.to_string(), format!("{:.1}%", score as f64 / max.max(1) as f64 * 100.0)),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        let _ = write!(s, "\n{:<width$}  {:>7}  {:>6}", name, score, share, width = width);
+    }
+    let plausible = &readings[0];
+    let _ = write!(s, "\nmost plausible: {}", names[0]);
+    let name = |i: usize| shapes[i].name.as_str();
+    let width = shapes.iter().map(|shape| shape.name.len()).max().unwrap().max("opponent".len());
+    let _ = write!(s, "\n{:>5}  {:<width$}  column  {:<width$}  outcome  {:>5}  {:<width$}  {:>4}",
+                   "round", "opponent", "you", "score", "best", "most", width = width);
+    for (i, round) in guide.iter().enumerate() {
+        let best = best(game, round.opponent);
+        let _ = write!(s, "\n{:>5}  {:<width$}  {:<6}  ", i + 1, name(round.opponent), round.column, width = width);
+        match play(game, round, &plausible.decoding) {
+            Some(play) => {
+                let outcome = format!("{:?}", play.outcome).to_lowercase();
+                let _ = write!(s, "{:<width$}  {:<7}  {:>5}  ", name(play.you), outcome, play.score, width = width);
+            }
+            None => {
+                let _ = write!(s, "{:<width$}  {:<7}  {:>5}  ", "-", "-", "-", width = width);
+            }
+        }
+        let _ = write!(s, "{:<width$}  {:>4}", name(best), game.score(round.opponent, best), width = width);
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::*;
+    use crate::game::RPSLS;
+    use crate::INPUT;
+
+    #[test]
+    fn test_readings() {
+        assert_eq!(permutations(3).collect::<Vec<_>>(),
+                   [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]]);
+        assert_eq!(permutations(5).count(), 120);
+        assert_eq!(permutations(0).collect::<Vec<_>>(), [Vec::<usize>::new()]);
+        let game = Game::standard();
+        let guide = game.parse_guide(INPUT).unwrap();
+        // paper beats rock, scissors paper and rock scissors: 8 + 9 + 7
+        assert_eq!(max_score(game, &guide), 24);
+        let readings = readings(game, &guide).unwrap();
+        assert_eq!(readings.len(), 7);
+        let score = |decoding: Decoding| readings.iter().find(|r| r.decoding == decoding).unwrap().score;
+        assert_eq!(score(Decoding::Shapes(vec![0, 1, 2])), Some(15));
+        assert_eq!(score(Decoding::Outcomes), Some(12));
+        // X=paper Y=rock Z=scissors plays the opponent's shape every round
+        assert_eq!(score(Decoding::Shapes(vec![1, 0, 2])), Some(4 + 5 + 6));
+        assert!(readings.windows(2).all(|w| w[0].score >= w[1].score));
+        assert_eq!(describe(game, &readings[0].decoding), "X=scissors Y=paper Z=rock");
+        assert_eq!(readings[0].score, Some(24));
+        assert_eq!(describe(game, &Decoding::Outcomes), "X=lose Y=draw Z=win");
+    }
+
+    #[test]
+    fn test_analyze() {
+        let game = Game::standard();
+        let report = analyze(game, &game.parse_guide(INPUT).unwrap()).unwrap();
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "3 rounds, scoring at most 24");
+        assert_eq!(lines[1], "reading                      score   share");
+        assert_eq!(lines[2], "X=scissors Y=paper Z=rock       24  100.0%");
+        assert_eq!(lines[7], "X=lose Y=draw Z=win             12   50.0%");
+        assert_eq!(lines[9], "most plausible: X=scissors Y=paper Z=rock");
+        assert_eq!(lines[10], "round  opponent  column  you       outcome  score  best      most");
+        assert_eq!(lines[11], "    1  rock      Y       paper     win          8  paper        8");
+        assert_eq!(lines.len(), 14);
+
+        let game = Game::parse(RPSLS).unwrap();
+        let report = analyze(&game, &game.parse_guide("A V\nE W").unwrap()).unwrap();
+        // V and W name no outcome
+        let last = report.lines().nth(1 + 121).unwrap();
+        assert_eq!(last.split_whitespace().collect::<Vec<_>>(), ["X=lose", "Y=draw", "Z=win", "-", "-"]);
+
+        // 11 shapes, each beating the five before it
+        let config = (0..11).map(|i| format!("shape s{} {} {} {}\n", i, i + 1, (b'A' + i) as char, (b'L' + i) as char))
+            .collect::<String>() + "lose 0 L\ndraw 3 M\nwin 6 N";
+        let game = Game::parse(&config).unwrap();
+        assert_eq!(analyze(&game, &game.parse_guide("A L").unwrap()).unwrap_err(),
+                   "11 shapes have 11! decodings, too many to analyze above 7 shapes");
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use common::{ParseError, Source};
+
+/// The game of the puzzle.
+pub const STANDARD: &str = "# each shape beats the one before it, and rock beats scissors
+shape rock 1 A X
+shape paper 2 B Y
+shape scissors 3 C Z
+lose 0 X
+draw 3 Y
+win 6 Z
+";
+
+/// Rock Paper Scissors Lizard Spock, with the shapes in an order where each beats the two before it.
+pub const RPSLS: &str = "shape rock 1 A V
+shape spock 2 B W
+shape paper 3 C X
+shape lizard 4 D Y
+shape scissors 5 E Z
+lose 0 X
+draw 3 Y
+win 6 Z
+";
+
+/// How a round ends for you.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+pub const OUTCOMES: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+/// A shape, with what playing it scores and the letters the opponent's and your column of a guide name it by.
+/// Scores are at most 65535, so that a round's score and the total of any guide fit the types they are summed in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub score: u16,
+    pub opponent: char,
+    pub you: char,
+}
+
+/// A game of an odd number of shapes in a cycle, each beating the half of the others listed just before it
+/// (wrapping around), so that every shape beats as many shapes as beat it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    /// The score and the guide's letter of each outcome, in the order of [`OUTCOMES`].
+    outcomes: [(u16, char); 3],
+}
+
+/// A round of a guide: the opponent's shape, as an index into the game's shapes, and the letter of the
+/// second column with the shape and the outcome the game names by it, if it does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub column: char,
+    pub shape: Option<usize>,
+    pub outcome: Option<Outcome>,
+}
+
+impl Game {
+    /// Parses a game from its config: a `shape <name> <score> <opponent letter> <your letter>` line per shape
+    /// in the order of the cycle, and a `<lose|draw|win> <score> <letter>` line per outcome. Blank lines and
+    /// lines starting with `#` are skipped.
+    pub fn parse(config: &str) -> Result<Game, ParseError> {
+        let src = Source::new(config);
+        let mut shapes: Vec<Shape> = vec![];
+        let mut outcomes = [None; 3];
+        for line in config.lines().filter(|line| !line.trim().is_empty() && !line.starts_with('#')) {
+            let mut tokens = line.split_whitespace();
+            let kind = src.next(&mut tokens, line, "shape, lose, draw or win")?;
+            let mut next = |expected| src.next(&mut tokens, line, expected);
+            match kind {
+                "shape" => {
+                    let name = next("a shape name")?;
+                    if shapes.iter().any(|shape| shape.name == name) {
+                        return Err(src.error(name, "a shape name not used before"));
+                    }
+                    let score = src.parse(next(SCORE)?, SCORE)?;
+                    let opponent = letter(&src, next("the opponent's letter")?, "the opponent's letter")?;
+                    let you = letter(&src, next("your letter")?, "your letter")?;
+                    shapes.push(Shape { name: name.to_string(), score, opponent, you });
+                }
+                "lose" | "draw" | "win" => {
+                    let i = ["lose", "draw", "win"].iter().position(|&k| k == kind).unwrap();
+                    if outcomes[i].is_some() {
+                        return Err(src.error(kind, "an outcome not given before"));
+                    }
+                    let score = src.parse(next(SCORE)?, SCORE)?;
+                    outcomes[i] = Some((score, letter(&src, next("the outcome's letter")?, "the outcome's letter")?));
+                }
+                _ => return Err(src.error(kind, "shape, lose, draw or win")),
+            }
+            if let Some(extra) = tokens.next() {
+                return Err(src.error(extra, "the end of the line"));
+            }
+        }
+        if shapes.len().is_multiple_of(2) {
+            return Err(src.missing(config.trim_end(), "an odd number of shapes, so that none is stronger than another"));
+        }
+        for column in [|shape: &Shape| shape.opponent, |shape: &Shape| shape.you] {
+            if shapes.iter().map(column).collect::<HashSet<_>>().len() < shapes.len() {
+                return Err(src.missing(config.trim_end(), "a different letter for each shape in each column"));
+            }
+        }
+        let [Some(lose), Some(draw), Some(win)] = outcomes else {
+            return Err(src.missing(config.trim_end(), "a lose, a draw and a win line"));
+        };
+        if lose.1 == draw.1 || draw.1 == win.1 || lose.1 == win.1 {
+            return Err(src.missing(config.trim_end(), "a different letter for each outcome"));
+        }
+        if shapes.len() < 3 {
+            return Err(src.missing(config.trim_end(), "at least 3 shapes, so that a round can be won and lost"));
+        }
+        Ok(Game { shapes, outcomes: [lose, draw, win] })
+    }
+
+    /// The game of the puzzle, parsed from [`STANDARD`] on first use.
+    pub fn standard() -> &'static Game {
+        static GAME: LazyLock<Game> = LazyLock::new(|| Game::parse(STANDARD).unwrap());
+        &GAME
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// How a round where you play `you` against `opponent` ends.
+    pub fn outcome(&self, opponent: usize, you: usize) -> Outcome {
+        let n = self.shapes.len();
+        match (you + n - opponent) % n {
+            0 => Outcome::Draw,
+            ahead if ahead <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// The score of a round where you play `you` against `opponent`.
+    pub fn score(&self, opponent: usize, you: usize) -> u32 {
+        let outcome = self.outcome(opponent, you);
+        let (outcome_score, _) = self.outcomes[OUTCOMES.iter().position(|&o| o == outcome).unwrap()];
+        u32::from(self.shapes[you].score) + u32::from(outcome_score)
+    }
+
+    /// The shape that ends a round against `opponent` with `outcome`; when several do, the one scoring most.
+    /// [`Game::parse`] only makes games where every outcome is possible, but a game of one shape only draws.
+    pub fn play_for(&self, opponent: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|&you| self.outcome(opponent, you) == outcome)
+            .max_by_key(|&you| self.shapes[you].score)
+    }
+
+    /// The shape the second column of a guide names, when it names your shape.
+    pub fn shape_of(&self, column: char) -> Option<usize> {
+        self.shapes.iter().position(|shape| shape.you == column)
+    }
+
+    /// The outcome the second column of a guide names, when it names how the round ends.
+    pub fn outcome_of(&self, column: char) -> Option<Outcome> {
+        self.outcomes.iter().position(|&(_, letter)| letter == column).map(|i| OUTCOMES[i])
+    }
+
+    /// The letter the second column of a guide names `outcome` by.
+    pub fn letter_of(&self, outcome: Outcome) -> char {
+        self.outcomes[OUTCOMES.iter().position(|&o| o == outcome).unwrap()].1
+    }
+
+    /// Parses a strategy guide of this game: a line per round, with the opponent's letter and then a
+    /// letter naming a shape, an outcome or both, decoded as both.
+    pub fn parse_guide(&self, input: &str) -> Result<Vec<Round>, ParseError> {
+        let src = Source::new(input);
+        let opponents = self.shapes.iter().map(|shape| shape.opponent.to_string()).collect::<Vec<_>>();
+        let mut columns = self.shapes.iter().map(|shape| shape.you).collect::<Vec<_>>();
+        columns.extend(self.outcomes.map(|(_, letter)| letter));
+        columns.sort_unstable();
+        columns.dedup();
+        let expected_opponent = one_of(&opponents);
+        let expected_column = one_of(&columns.iter().map(|c| c.to_string()).collect::<Vec<_>>());
+        input.lines().map(|line| {
+            let mut split = line.split(' ');
+            let opponent = src.next(&mut split, line, &expected_opponent)?;
+            let opponent = opponents.iter().position(|o| o == opponent)
+                .ok_or_else(|| src.error(opponent, expected_opponent.as_str()))?;
+            let token = src.next(&mut split, line, &expected_column)?;
+            let round = token.parse::<char>().ok().map(|column| {
+                Round { opponent, column, shape: self.shape_of(column), outcome: self.outcome_of(column) }
+            });
+            match round {
+                Some(round) if round.shape.is_some() || round.outcome.is_some() => Ok(round),
+                _ => Err(src.error(token, expected_column.as_str())),
+            }
+        }).collect()
+    }
+
+    /// The total score of following `guide`, reading its second column as the shape to play, unless a
+    /// round's letter names no shape.
+    pub fn score1(&self, guide: &[Round]) -> Option<u64> {
+        guide.iter().map(|round| Some(u64::from(self.score(round.opponent, round.shape?)))).sum()
+    }
+
+    /// The total score of following `guide`, reading its second column as how the round must end, unless a
+    /// round's letter names no outcome.
+    pub fn score2(&self, guide: &[Round]) -> Option<u64> {
+        guide.iter().map(|round| {
+            Some(u64::from(self.score(round.opponent, self.play_for(round.opponent, round.outcome?)?)))
+        }).sum()
+    }
+}
+
+const SCORE: &str = "a score from 0 to 65535";
+
+fn letter(src: &Source, token: &str, expected: &str) -> Result<char, ParseError> {
+    token.parse::<char>().map_err(|_| src.error(token, format!("{}, a single character", expected)))
+}
+
+/// `A, B or C` for the alternatives `A`, `B` and `C`.
+fn one_of(alternatives: &[String]) -> String {
+    match alternatives {
+        [] => "nothing".to_string(),
+        [one] => one.clone(),
+        [init @ .., last] => format!("{} or {}", init.join(", "), last),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::*;
+
+    #[test]
+    fn test_standard() {
+        let game = Game::standard();
+        assert_eq!(game.outcome(0, 1), Outcome::Win); // paper beats rock
+        assert_eq!(game.outcome(2, 0), Outcome::Win); // rock beats scissors
+        assert_eq!(game.outcome(1, 0), Outcome::Lose);
+        assert_eq!(game.play_for(0, Outcome::Lose), Some(2));
+        let guide = game.parse_guide("A Y\nB X\nC Z").unwrap();
+        assert_eq!((game.score1(&guide), game.score2(&guide)), (Some(15), Some(12)));
+        let e = game.parse_guide("A Y\nD X").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 1: expected A, B or C, found \"D\"");
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let game = Game::parse(RPSLS).unwrap();
+        let names = |you: usize| game.shapes().iter().enumerate()
+            .filter(|&(opponent, _)| game.outcome(opponent, you) == Outcome::Lose)
+            .map(|(_, shape)| shape.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names(0), ["spock", "paper"]); // what beats rock
+        assert_eq!(names(1), ["paper", "lizard"]); // what beats spock
+        // a win against rock is spock or paper, and paper scores more
+        assert_eq!(game.play_for(0, Outcome::Win), Some(2));
+        let guide = game.parse_guide("A V\nE W\nD Z").unwrap();
+        assert_eq!(game.score1(&guide), Some(1 + 3 + 2 + 6 + 5 + 6));
+        assert_eq!(game.score2(&guide), None); // V and W name no outcome
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |config: &str| Game::parse(config).unwrap_err().to_string();
+        assert_eq!(error("shape rock 1 A X\nshape paper 2 B Y\nlose 0 X\ndraw 3 Y\nwin 6 Z"),
+                   "line 5, column 8: expected an odd number of shapes, so that none is stronger than another, \
+                    found end of line");
+        assert_eq!(error("shape rock 1 A X\nlose 0 X\ndraw 3 Y\nwin 6 Z"),
+                   "line 4, column 8: expected at least 3 shapes, so that a round can be won and lost, \
+                    found end of line");
+        let single = Game { shapes: vec![Shape { name: "rock".to_string(), score: 1, opponent: 'A', you: 'X' }],
+                            outcomes: [(0, 'X'), (3, 'Y'), (6, 'Z')] };
+        assert_eq!(single.play_for(0, Outcome::Win), None);
+        let round = Round { opponent: 0, column: 'Z', shape: None, outcome: Some(Outcome::Win) };
+        assert_eq!(single.score2(&[round]), None);
+        assert_eq!(error("shape rock 1 A X\nshape rock 2 B Y"),
+                   "line 2, column 7: expected a shape name not used before, found \"rock\"");
+        assert_eq!(error("shape rock x A X"), "line 1, column 12: expected a score from 0 to 65535, found \"x\"");
+        assert_eq!(error("shape rock 4294967295 A X"),
+                   "line 1, column 12: expected a score from 0 to 65535, found \"4294967295\"");
+        // the highest scores add up without overflowing
+        let config = STANDARD.replace(" 1 A", " 65535 A").replace("win 6", "win 65535");
+        let game = Game::parse(&config).unwrap();
+        let guide = vec![game.parse_guide("C X").unwrap()[0]; 70000];
+        assert_eq!(game.score1(&guide), Some(70000 * (65535 + 65535)));
+        assert_eq!(error("shape rock 1 AB X"),
+                   "line 1, column 14: expected the opponent's letter, a single character, found \"AB\"");
+        assert_eq!(error("shape rock 1 A X\nlose 0 X\nwin 6 Z"),
+                   "line 3, column 8: expected a lose, a draw and a win line, found end of line");
+        assert_eq!(error("tie 3 Y"), "line 1, column 1: expected shape, lose, draw or win, found \"tie\"");
+        assert_eq!(error("win 6 Z Z"), "line 1, column 9: expected the end of the line, found \"Z\"");
+    }
+}
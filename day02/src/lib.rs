@@ -0,0 +1,104 @@
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+pub use game::{Game, Outcome, Round, Shape, OUTCOMES};
+
+pub mod analysis;
+pub mod game;
+
+/// A round of the puzzle's guide as both parts read it, with shapes as indices into the standard game's:
+/// the opponent's shape, the shape the second column names, and the shape ending the round as it says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Strategy {
+    pub opponent: usize,
+    pub shape: usize,
+    pub play: usize,
+}
+
+/// Parses each line into the opponent's shape and what the second column means for either part, decoded
+/// through the puzzle's game.
+pub fn parse(input: &str) -> Result<Vec<Strategy>, ParseError> {
+    let src = Source::new(input);
+    let game = Game::standard();
+    game.parse_guide(input)?.into_iter().zip(input.lines()).map(|(round, line)| {
+        let play = round.outcome.and_then(|outcome| game.play_for(round.opponent, outcome));
+        match (round.shape, play) {
+            (Some(shape), Some(play)) => Ok(Strategy { opponent: round.opponent, shape, play }),
+            _ => {
+                let column = line.split_once(' ').map_or(line, |(_, column)| column);
+                Err(src.error(column, "a letter naming both a shape and an outcome"))
+            }
+        }
+    }).collect()
+}
+
+pub fn score1(guide: &[Strategy]) -> u64 {
+    let game = Game::standard();
+    guide.iter().map(|round| u64::from(game.score(round.opponent, round.shape))).sum()
+}
+
+pub fn score2(guide: &[Strategy]) -> u64 {
+    let game = Game::standard();
+    guide.iter().map(|round| u64::from(game.score(round.opponent, round.play))).sum()
+}
+
+/// A guide of `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", rng.choose(&["A", "B", "C"]), rng.choose(&["X", "Y", "Z"]))).collect()
+}
+
+const INPUT: &str = "A Y
+B X
+C Z";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Strategy>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("15"), Some("12")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        score1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        score2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_score1() {
+        assert_eq!(score1(&parse(INPUT).unwrap()), 15);
+    }
+
+    #[test]
+    fn test_score2() {
+        assert_eq!(score2(&parse(INPUT).unwrap()), 12);
+    }
+
+    #[test]
+    fn test_parse() {
+        // rock against Y, which names paper and a draw, played with rock
+        assert_eq!(parse(INPUT).unwrap()[0], Strategy { opponent: 0, shape: 1, play: 0 });
+        let e = parse("A Y\nB W").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 3: expected X, Y or Z, found \"W\"");
+    }
+}
@@ -1,83 +1,59 @@
-use std::fs;
+use std::{fs, process};
 
-#[derive(Debug, Clone, Copy)]
-enum Shape {
-    Rock = 1,
-    Paper = 2,
-    Scissors = 3,
-}
-
-struct Round {
-    opponent: Shape,
-    you: Shape,
-}
+use common::InputSource;
+use day02::{analysis, Day02, Game};
 
-// https://rust-lang.github.io/rfcs/2005-match-ergonomics.html
+const USAGE: &str = "usage: [-v|-vv|-vvv] [--format <text|json>] [path|-]
+       [--game <config>] [--analyze] [path|-]";
 
-fn score1(input: &String) -> u32 {
-    let rounds: Vec<_> = input.lines().map(|line| {
-        let shapes: Vec<_> = line.chars().filter(|&c| c != ' ').collect();
-        let opponent = match shapes[0] {
-            'A' => Shape::Rock,
-            'B' => Shape::Paper,
-            'C' => Shape::Scissors,
-            _ => unreachable!(),
-        };
-        let you = match shapes[1] {
-            'X' => Shape::Rock,
-            'Y' => Shape::Paper,
-            'Z' => Shape::Scissors,
-            _ => unreachable!(),
-        };
-        Round { opponent, you }
-    }).collect();
-    rounds.iter().map(|r| score_single_round(r.opponent, r.you)).sum()
-}
-
-fn score2(input: &String) -> u32 {
-    let rounds: Vec<_> = input.lines().map(|line| {
-        let shapes: Vec<_> = line.chars().filter(|&c| c != ' ').collect();
-        let opponent = match shapes[0] {
-            'A' => Shape::Rock,
-            'B' => Shape::Paper,
-            'C' => Shape::Scissors,
-            _ => unreachable!(),
-        };
-        let you = match shapes[1] {
-            'X' => { // lose
-                match opponent {
-                    Shape::Rock => Shape::Scissors,
-                    Shape::Paper => Shape::Rock,
-                    Shape::Scissors => Shape::Paper,
-                }
-            }
-            'Y' => { // draw
-                opponent
-            }
-            'Z' => { // win
-                match opponent {
-                    Shape::Rock => Shape::Paper,
-                    Shape::Paper => Shape::Scissors,
-                    Shape::Scissors => Shape::Rock,
-                }
-            }
-            _ => unreachable!(),
-        };
-        Round { opponent, you }
-    }).collect();
-    rounds.iter().map(|r| score_single_round(r.opponent, r.you)).sum()
+/// `--game <config>` scores the guide as a game of the shapes and scores the config file describes, see
+/// [`Game::parse`], reading the second column as a shape, and as an outcome when it names one. `--analyze`
+/// scores every way of reading the second column instead.
+fn main() {
+    let args = common::log::init(false, &[common::VALUED_FLAGS, &["--game"]].concat());
+    if !args.iter().any(|arg| arg == "--game" || arg == "--analyze") {
+        common::main_with::<Day02>(&args);
+        return;
+    }
+    if let Err(e) = parse_args(&args).and_then(|(config, analyze, path)| score(config, analyze, path)) {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
 
-fn score_single_round(opponent: Shape, you: Shape) -> u32 {
-    match (opponent, you) {
-        (Shape::Rock, Shape::Paper) | (Shape::Paper, Shape::Scissors) | (Shape::Scissors, Shape::Rock) => you as u32 + 6,
-        (Shape::Rock, Shape::Rock) | (Shape::Paper, Shape::Paper) | (Shape::Scissors, Shape::Scissors) => you as u32 + 3,
-        (Shape::Rock, Shape::Scissors) | (Shape::Paper, Shape::Rock) | (Shape::Scissors, Shape::Paper) => you as u32 + 0,
+/// Parses the `[--game <config>] [--analyze] [path|-]` arguments.
+fn parse_args(args: &[String]) -> Result<(Option<&str>, bool, Option<&str>), String> {
+    let (mut config, mut analyze, mut path) = (None, false, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--game" => config = Some(args.next().ok_or(format!("missing value for --game\n{}", USAGE))?.as_str()),
+            "--analyze" => analyze = true,
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {}\n{}", arg, USAGE)),
+        }
     }
+    Ok((config, analyze, path))
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    println!("score1:{:?}", score1(&input));
-    println!("score2:{:?}", score2(&input));
+fn score(config: Option<&str>, analyze: bool, path: Option<&str>) -> Result<(), String> {
+    let game = match config {
+        Some(config) => {
+            let game = fs::read_to_string(config).map_err(|e| format!("cannot read {}: {}", config, e))?;
+            Game::parse(&game).map_err(|e| format!("{}: {}", config, e))?
+        }
+        None => Game::standard().clone(),
+    };
+    let source = InputSource::resolve(2, path);
+    let guide = game.parse_guide(&source.read()?).map_err(|e| format!("{}: {}", source, e))?;
+    if analyze {
+        println!("{}", analysis::analyze(&game, &guide)?);
+        return Ok(());
+    }
+    let answers = [(game.score1(&guide), "a shape"), (game.score2(&guide), "an outcome")];
+    for (part, (answer, name)) in (1..).zip(answers) {
+        let answer = answer.map_or(format!("none, the second column does not always name {}", name), |s| s.to_string());
+        println!("{}", common::format_answer(part, &answer));
+    }
+    Ok(())
 }
@@ -0,0 +1,237 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+pub mod validate;
+
+/// How many elves a group of the puzzle has.
+pub const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+/// A set of item types, as a bit per type in the order of their priorities: `a` to `z`, then `A` to `Z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Items(u64);
+
+impl Items {
+    /// Every item type.
+    pub const ALL: Items = Items((1 << 52) - 1);
+
+    /// The item types among `items`, which must all be letters, as the parser and the validator make sure.
+    pub(crate) fn of(items: &str) -> Items {
+        Items(items.bytes().fold(0, |mask, item| mask | 1 << (priority(item) - 1)))
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item types, in the order of their priorities.
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        (b'a'..=b'z').chain(b'A'..=b'Z').enumerate().filter(move |&(i, _)| self.0 & 1 << i != 0).map(|(_, c)| c as char)
+    }
+
+    /// The sum of the priorities of the item types.
+    pub fn priority(self) -> u32 {
+        (0..52).filter(|i| self.0 & 1 << i != 0).map(|i| i + 1).sum()
+    }
+}
+
+/// The item types as letters, e.g. `pL`.
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|item| write!(f, "{}", item))
+    }
+}
+
+/// The item types in each compartment of a rucksack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub first: Items,
+    pub second: Items,
+}
+
+impl Rucksack {
+    /// A rucksack packed with `items`, the first half of them in the first compartment. The items must all
+    /// be letters, as the parser makes sure.
+    pub(crate) fn new(items: &str) -> Rucksack {
+        let (first, second) = items.split_at(items.len() / 2);
+        Rucksack { first: Items::of(first), second: Items::of(second) }
+    }
+
+    pub fn items(&self) -> Items {
+        self.first.union(self.second)
+    }
+
+    /// The item types packed in both compartments, which should be just one.
+    pub fn shared(&self) -> Items {
+        self.first.intersection(self.second)
+    }
+}
+
+/// The item types every rucksack of a group carries, which should be just its badge.
+pub fn badge(group: &[Rucksack]) -> Items {
+    group.iter().fold(Items::ALL, |badge, rucksack| badge.intersection(rucksack.items()))
+}
+
+/// Parses a rucksack per line, in groups of `group_size`, reporting the rucksacks missing from an incomplete
+/// last group.
+pub fn parse_groups(input: &str, group_size: NonZeroUsize) -> Result<Vec<Rucksack>, ParseError> {
+    let src = Source::new(input);
+    let rucksacks = input.lines().map(|line| {
+        src.check_chars(line, |c| c.is_ascii_alphabetic(), "an item letter")?;
+        Ok(Rucksack::new(line))
+    }).collect::<Result<Vec<_>, _>>()?;
+    let left = rucksacks.len() % group_size;
+    if left != 0 {
+        let missing = group_size.get() - left;
+        let missing = format!("{} more rucksack(s) to complete the last group of {}", missing, group_size);
+        return Err(src.missing(input.trim_end(), missing));
+    }
+    Ok(rucksacks)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    parse_groups(input, GROUP_SIZE)
+}
+
+pub fn puzzle1(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks.iter().map(|rucksack| rucksack.shared().priority()).sum()
+}
+
+/// The sum of the priorities of the badges of the groups of `group_size` rucksacks.
+pub fn badges(rucksacks: &[Rucksack], group_size: NonZeroUsize) -> u32 {
+    rucksacks.chunks(group_size.get()).map(|group| badge(group).priority()).sum()
+}
+
+pub fn puzzle2(rucksacks: &[Rucksack]) -> u32 {
+    badges(rucksacks, GROUP_SIZE)
+}
+
+/// The priority of an item type, 1 to 26 for `a` to `z` and 27 to 52 for `A` to `Z`.
+fn priority(item: u8) -> u32 {
+    match item {
+        b'a'..=b'z' => u32::from(item - b'a') + 1,
+        b'A'..=b'Z' => u32::from(item - b'A') + 27,
+        _ => panic!("{:?} is not an item type", item as char),
+    }
+}
+
+/// `size` groups of three rucksacks, each with one item in both compartments and one badge per group.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut items = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        let badge = items.pop().unwrap();
+        // every elf packs from its own 17 items, 8 for the first compartment and 9 for the second,
+        // so the only item the three share is the badge, which goes in the first compartment
+        for own in items.chunks(17) {
+            let (first, second) = own.split_at(8);
+            let len = rng.range(2..=16);
+            let mut a = [first[0], badge].into_iter().chain((2..len).map(|_| *rng.choose(first))).collect::<Vec<_>>();
+            let mut b = [first[0]].into_iter().chain((1..len).map(|_| *rng.choose(second))).collect::<Vec<_>>();
+            rng.shuffle(&mut a);
+            rng.shuffle(&mut b);
+            input.extend(a.into_iter().chain(b));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("157"), Some("70")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn size(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 157);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 70);
+    }
+
+    #[test]
+    fn test_items() {
+        let rucksacks = parse(INPUT).unwrap();
+        assert_eq!(rucksacks.iter().map(|r| r.shared().to_string()).collect::<Vec<_>>(), ["p", "L", "P", "v", "t", "s"]);
+        assert_eq!(badge(&rucksacks[..3]).to_string(), "r");
+        assert_eq!(Items::of("zaAZa").to_string(), "azAZ");
+        assert_eq!(Items::of("zaAZa").priority(), 1 + 26 + 27 + 52);
+        assert_eq!(Items::ALL.len(), 52);
+        assert!(badge(&[Rucksack::new("ab"), Rucksack::new("cd")]).is_empty());
+    }
+
+    #[test]
+    fn test_groups() {
+        let rucksacks = parse_groups(INPUT, size(2)).unwrap();
+        // the badges of pairs are all the items the pairs happen to share
+        let pairs = rucksacks.chunks(2).map(|pair| badge(pair).to_string()).collect::<Vec<_>>();
+        assert_eq!(pairs, ["frsFM", "qvwBT", "GJZ"]);
+        assert_eq!(badges(&rucksacks, size(2)), ["frsFM", "qvwBT", "GJZ"].iter().map(|&s| Items::of(s).priority()).sum::<u32>());
+        assert_eq!(badges(&rucksacks, size(6)), 0);
+        let e = parse_groups(INPUT, size(4)).unwrap_err();
+        assert_eq!(e.to_string(), "line 6, column 25: expected 2 more rucksack(s) to complete the last group of 4, found end of line");
+        assert!(parse("ab\ncd").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        // every rucksack shares one item between its compartments, and every group one badge
+        common::fuzz::check(0..50, |rng| assert!(validate::validate(&generate(rng, 10), GROUP_SIZE).is_empty()));
+    }
+}
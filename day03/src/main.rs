@@ -1,74 +1,53 @@
-use std::collections::HashSet;
-use std::fs;
+use std::num::NonZeroUsize;
+use std::process;
 
-fn puzzle1(input: &str) -> u32 {
-    input.lines().map(|line| {
-        let items: Vec<_> = line.chars().collect::<Vec<_>>();
-        let (first, second) = items.split_at(items.len() / 2);
-        let first: HashSet<_> = first.iter().copied().collect();
-        let second: HashSet<_> = second.iter().copied().collect();
-        priority(first.intersection(&second).copied())
-    }).sum()
-}
-
-fn puzzle2(input: &str) -> u32 {
-    input.lines().collect::<Vec<_>>().chunks_exact(3) // no remainder
-        .map(|group| {
-            let badge = group.iter()
-                .map(|line| line.chars().collect::<HashSet<_>>())
-                .fold(None, |acc: Option<HashSet<char>>, hs| {
-                    if let Some(acc) = acc {
-                        Some(acc.intersection(&hs).copied().collect())
-                    } else {
-                        Some(hs)
-                    }
-                }).unwrap();
-            priority(badge.into_iter())
-        }).sum()
-}
-
-fn priority(items: impl Iterator<Item=char>) -> u32 {
-    items.map(|item| {
-        let p = match item {
-            'a'..='z' => item as u8 - 'a' as u8 + 1,
-            'A'..='Z' => item as u8 - 'A' as u8 + 27,
-            _ => unreachable!(),
-        };
-        p as u32
-    }).sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
+use common::InputSource;
+use day03::{validate, Day03, GROUP_SIZE};
 
-    const INPUT1: &'static str = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
+const USAGE: &str = "usage: [-v|-vv|-vvv] [--format <text|json>] [path|-]
+       --validate [--group <size>] [path|-]";
 
-    const INPUT2: &'static str = "vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw";
-
-    #[test]
-    fn test_puzzle1() {
-        assert_eq!(puzzle1(INPUT1), 157);
+/// `--validate` lists every problem with the rucksacks instead of solving, see [`validate::validate`], and
+/// fails when there is any. `--group` sets how many rucksacks a group has.
+fn main() {
+    let args = common::log::init(false, &[common::VALUED_FLAGS, &["--group"]].concat());
+    if !args.iter().any(|arg| arg == "--validate") {
+        common::main_with::<Day03>(&args);
+        return;
+    }
+    match parse_args(&args).and_then(|(group_size, path)| check(group_size, path)) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
     }
+}
 
-    #[test]
-    fn test_puzzle2() {
-        assert_eq!(puzzle2(INPUT2), 70);
+/// Parses the `--validate [--group <size>] [path|-]` arguments.
+fn parse_args(args: &[String]) -> Result<(NonZeroUsize, Option<&str>), String> {
+    let (mut group_size, mut path) = (GROUP_SIZE, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--validate" => {}
+            "--group" => {
+                let size = args.next().ok_or(format!("missing value for --group\n{}", USAGE))?;
+                group_size = size.parse()
+                    .map_err(|_| format!("invalid group size {}, expected a positive number", size))?;
+            }
+            _ if path.is_none() => path = Some(arg.as_str()),
+            _ => return Err(format!("unexpected argument {}\n{}", arg, USAGE)),
+        }
     }
+    Ok((group_size, path))
 }
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    println!("puzzle1:{:?}", puzzle1(&input));
-    println!("puzzle2:{:?}", puzzle2(&input));
+/// Prints the validation report, returning whether the rucksacks are free of problems.
+fn check(group_size: NonZeroUsize, path: Option<&str>) -> Result<bool, String> {
+    let input = InputSource::resolve(3, path).read()?;
+    let issues = validate::validate(&input, group_size);
+    println!("{}", validate::report(&input, &issues));
+    Ok(issues.is_empty())
 }
@@ -0,0 +1,144 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::{Items, Rucksack};
+
+/// Something wrong with a rucksack or a group of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The rucksack holds an odd number of items, so its compartments cannot be told apart.
+    OddLength(usize),
+    /// The rucksack holds what is not an item letter, with the column of each, from 1.
+    InvalidItems(Vec<(usize, char)>),
+    /// Either no item type or several are in both compartments.
+    Shared(Items),
+    /// Either no item type or several are carried by every rucksack of the group.
+    Badges(Items),
+    /// The last group has only this many rucksacks.
+    IncompleteGroup(usize),
+}
+
+/// A problem with the rucksacks on some lines, counting from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub lines: RangeInclusive<usize>,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::OddLength(len) => write!(f, "odd number of items ({})", len),
+            Problem::InvalidItems(items) => {
+                let items = items.iter().map(|(column, c)| format!("{:?} at column {}", c, column)).collect::<Vec<_>>();
+                write!(f, "not an item letter: {}", items.join(", "))
+            }
+            Problem::Shared(items) if items.is_empty() => write!(f, "no item in both compartments"),
+            Problem::Shared(items) => write!(f, "{} items in both compartments: {}", items.len(), items),
+            Problem::Badges(items) if items.is_empty() => write!(f, "no badge"),
+            Problem::Badges(items) => write!(f, "{} possible badges: {}", items.len(), items),
+            Problem::IncompleteGroup(len) => write!(f, "incomplete group of {} rucksack(s)", len),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.lines.start() == self.lines.end() {
+            write!(f, "line {}: {}", self.lines.start(), self.problem)
+        } else {
+            write!(f, "lines {}-{}: {}", self.lines.start(), self.lines.end(), self.problem)
+        }
+    }
+}
+
+/// Every problem with the rucksacks of `input` in groups of `group_size`, in the order of their lines. Only
+/// the item letters of a line count towards what it shares with its group, and the compartments of a line of
+/// odd length are not checked for shared items.
+pub fn validate(input: &str, group_size: NonZeroUsize) -> Vec<Issue> {
+    let group_size = group_size.get();
+    let mut issues = vec![];
+    let mut group = vec![];
+    let lines = input.lines().collect::<Vec<_>>();
+    for (i, line) in lines.iter().enumerate() {
+        let n = i + 1;
+        let chars = line.chars().collect::<Vec<_>>();
+        let invalid = (1..).zip(&chars).filter(|(_, c)| !c.is_ascii_alphabetic()).map(|(column, &c)| (column, c))
+            .collect::<Vec<_>>();
+        if !invalid.is_empty() {
+            issues.push(Issue { lines: n..=n, problem: Problem::InvalidItems(invalid) });
+        }
+        let letters = |chars: &[char]| Items::of(&chars.iter().filter(|c| c.is_ascii_alphabetic()).collect::<String>());
+        if chars.len() % 2 == 1 {
+            issues.push(Issue { lines: n..=n, problem: Problem::OddLength(chars.len()) });
+        } else {
+            let (first, second) = chars.split_at(chars.len() / 2);
+            let shared = Rucksack { first: letters(first), second: letters(second) }.shared();
+            if shared.len() != 1 {
+                issues.push(Issue { lines: n..=n, problem: Problem::Shared(shared) });
+            }
+        }
+        group.push(letters(&chars));
+        if group.len() == group_size {
+            let badges = group.drain(..).fold(Items::ALL, Items::intersection);
+            if badges.len() != 1 {
+                issues.push(Issue { lines: n + 1 - group_size..=n, problem: Problem::Badges(badges) });
+            }
+        }
+    }
+    if !group.is_empty() {
+        let lines = lines.len() + 1 - group.len()..=lines.len();
+        issues.push(Issue { lines, problem: Problem::IncompleteGroup(group.len()) });
+    }
+    issues
+}
+
+/// A line per problem with the rucksacks of `input`, as [`validate`] found `issues`, and how many there are.
+pub fn report(input: &str, issues: &[Issue]) -> String {
+    let rucksacks = input.lines().count();
+    let mut lines = issues.iter().map(Issue::to_string).collect::<Vec<_>>();
+    lines.push(match issues.len() {
+        0 => format!("{} rucksacks, no problems", rucksacks),
+        n => format!("{} rucksacks, {} problem(s)", rucksacks, n),
+    });
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validate::*;
+    use crate::{GROUP_SIZE, INPUT};
+
+    const PAIRS: NonZeroUsize = NonZeroUsize::new(2).unwrap();
+
+    #[test]
+    fn test_valid() {
+        assert!(validate(INPUT, GROUP_SIZE).is_empty());
+        assert_eq!(report(INPUT, &[]), "6 rucksacks, no problems");
+        // pairs of the example share several items
+        let issues = validate(INPUT, PAIRS);
+        assert_eq!(issues[0], Issue { lines: 1..=2, problem: Problem::Badges(Items::of("frsFM")) });
+        assert_eq!(issues.len(), 3);
+    }
+
+    #[test]
+    fn test_problems() {
+        let input = "abcab\naa1b\nabcd\nabab\nxy é";
+        let report = report(input, &validate(input, PAIRS));
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines, [
+            "line 1: odd number of items (5)",
+            "line 2: not an item letter: '1' at column 3",
+            "line 2: no item in both compartments",
+            "lines 1-2: 2 possible badges: ab",
+            "line 3: no item in both compartments",
+            "line 4: 2 items in both compartments: ab",
+            "lines 3-4: 2 possible badges: ab",
+            "line 5: not an item letter: ' ' at column 3, 'é' at column 4",
+            "line 5: no item in both compartments",
+            "line 5: incomplete group of 1 rucksack(s)",
+            "5 rucksacks, 10 problem(s)",
+        ]);
+    }
+}
@@ -0,0 +1,102 @@
+use common::interval::Interval;
+use common::{Example, ParseError, Rng, Solution, Source};
+
+/// A pair's section range.
+pub type Pair = Interval<u32>;
+
+pub fn parse(input: &str) -> Result<Vec<(Pair, Pair)>, ParseError> {
+    let src = Source::new(input);
+    input.lines().map(|line| {
+        let (a, b) = src.split_once(line, ",")?;
+        Ok((Pair::parse(&src, a, "a section number")?, Pair::parse(&src, b, "a section number")?))
+    }).collect()
+}
+
+pub fn puzzle1(pairs: &[(Pair, Pair)]) -> u32 {
+    pairs.iter().map(|(a, b)| (a.contains(b) || b.contains(a)) as u32).sum()
+}
+
+pub fn puzzle2(pairs: &[(Pair, Pair)]) -> u32 {
+    pairs.iter().map(|(a, b)| a.overlap(b) as u32).sum()
+}
+
+/// `size` pairs of section ranges from 1 to 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let [a, b, c, d] = [(); 4].map(|_| rng.range(1..=99));
+        format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d))
+    }).collect()
+}
+
+const INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Pair, Pair)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("2"), Some("4")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse("2-4,6-8\n2-3;4-5").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 8: expected \",\", found end of line");
+        let e = parse("2-4,6-8\n2-3,4-").unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 7: expected a section number, found end of line");
+        assert_eq!("7-x".parse::<Pair>().unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_pair_from_str() {
+        common::fuzz::check(0..1000, |rng| {
+            let (start, end) = (rng.range(0..=u32::MAX), rng.range(0..=u32::MAX));
+            let pair = format!("{}-{}", start, end).parse::<Pair>().unwrap();
+            assert_eq!((pair.start, pair.end), (start, end));
+            let s = common::fuzz::bytes(rng, 12);
+            assert_eq!(s.parse::<Pair>().is_ok(), s.split_once('-').is_some_and(|(a, b)| {
+                a.parse::<u32>().is_ok() && b.parse::<u32>().is_ok()
+            }));
+        });
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+/// The crate stacks, bottom first, and the moves as `[count, from, to]`.
+pub type Procedure = (Vec<VecDeque<char>>, Vec<Vec<usize>>);
+
+pub fn parse_input(input: &str) -> Result<Procedure, ParseError> {
+    let src = Source::new(input);
+    let (drawing, moves) = src.split_once(input, "\n\n")?;
+    let stacks = drawing.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    if !stacks.last().is_some_and(|numbers| numbers.iter().any(|c| c.is_ascii_digit())) {
+        return Err(src.missing(drawing, "a line of stack numbers"));
+    }
+    let stacks = stacks[stacks.len() - 1].iter().enumerate()
+        .filter(|(_, &c)| c.is_ascii_digit())
+        .map(|(j, _)| {
+            (0..stacks.len() - 1).rev()
+                .flat_map(|i| stacks[i].get(j))
+                .filter(|c| c.is_ascii_alphabetic())
+                .copied()
+                .collect::<VecDeque<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let stack = |s: &str| match s.parse::<usize>() {
+        Ok(n) if (1..=stacks.len()).contains(&n) => Ok(n),
+        _ => Err(src.error(s, format!("a stack number from 1 to {}", stacks.len()))),
+    };
+    let moves = moves.lines().map(|line| { // (num, from, to)
+        let (num, rest) = src.split_once(src.prefix(line, "move ")?, " from ")?;
+        let (from, to) = src.split_once(rest, " to ")?;
+        Ok(vec![src.parse::<usize>(num, "a crate count")?, stack(from)?, stack(to)?])
+    }).collect::<Result<Vec<_>, _>>()?;
+
+    Ok((stacks, moves))
+}
+
+pub fn puzzle1(stacks: &[VecDeque<char>], moves: &[Vec<usize>]) -> String {
+    let mut stacks = stacks.to_vec();
+    moves.iter().for_each(|m| {
+        (0..m[0]).for_each(|_| {
+            let pop = stacks[m[1] - 1].pop_back().unwrap();
+            stacks[m[2] - 1].push_back(pop);
+        })
+    });
+    stacks.iter_mut().map(|stack| stack.pop_back().unwrap()).collect()
+}
+
+pub fn puzzle2(stacks: &[VecDeque<char>], moves: &[Vec<usize>]) -> String {
+    let mut stacks = stacks.to_vec();
+    let mut temp = VecDeque::new();
+    moves.iter().for_each(|m| {
+        (0..m[0]).for_each(|_| {
+            let pop = stacks[m[1] - 1].pop_back().unwrap();
+            temp.push_back(pop);
+        });
+        (0..m[0]).for_each(|_| {
+            let pop = temp.pop_back().unwrap();
+            stacks[m[2] - 1].push_back(pop);
+        });
+    });
+    stacks.iter_mut().map(|stack| stack.pop_back().unwrap()).collect()
+}
+
+/// Draws the stacks and lists the moves as the puzzle input does, so that [`parse_input`] reads them back.
+pub fn format_input(stacks: &[VecDeque<char>], moves: &[Vec<usize>]) -> String {
+    let height = stacks.iter().map(VecDeque::len).max().unwrap_or(0);
+    let mut input = (0..height).rev().map(|level| {
+        stacks.iter().map(|stack| match stack.get(level) {
+            Some(c) => format!("[{}]", c),
+            None => "   ".to_string(),
+        }).collect::<Vec<_>>().join(" ") + "\n"
+    }).collect::<String>();
+    input += &(1..=stacks.len()).map(|i| format!(" {} ", i)).collect::<Vec<_>>().join(" ");
+    input += "\n\n";
+    for m in moves {
+        input += &format!("move {} from {} to {}\n", m[0], m[1], m[2]);
+    }
+    input
+}
+
+/// Nine stacks and `size` moves, none of which empties a stack.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let ((drawn, moves), _) = plant(rng, size);
+    format_input(&drawn, &moves)
+}
+
+/// The procedure [`generate`] draws and moves, with the crates on top of the stacks after moving several
+/// at once, the answer to part 2.
+fn plant(rng: &mut Rng, size: usize) -> (Procedure, String) {
+    let mut stacks = (0..9).map(|_| {
+        let height = rng.range(2..=8);
+        rng.word("ABCDEFGHIJKLMNOPQRSTUVWXYZ", height).chars().collect::<VecDeque<_>>()
+    }).collect::<Vec<_>>();
+    let drawn = stacks.clone();
+    let moves = (0..size).map(|_| {
+        // there are more crates than stacks, so some stack always has one to spare
+        let from = loop {
+            let from = rng.range(0..=stacks.len() - 1);
+            if stacks[from].len() > 1 {
+                break from;
+            }
+        };
+        let to = (from + rng.range(1..=stacks.len() - 1)) % stacks.len();
+        let left = rng.range(1..=stacks[from].len() - 1);
+        let moved = stacks[from].split_off(left);
+        stacks[to].extend(&moved);
+        vec![moved.len(), from + 1, to + 1]
+    }).collect::<Vec<_>>();
+    let tops = stacks.iter().map(|stack| stack.back().unwrap()).collect();
+    ((drawn, moves), tops)
+}
+
+const INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("CMZ"), Some("MCD")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        puzzle1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        puzzle2(&input.0, &input.1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        let (stacks, moves) = parse_input(INPUT).unwrap();
+        assert_eq!(puzzle1(&stacks, &moves), "CMZ".to_string());
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        let (stacks, moves) = parse_input(INPUT).unwrap();
+        assert_eq!(puzzle2(&stacks, &moves), "MCD".to_string());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input(&INPUT.replace("move 2 from 2", "move 2 from 4")).unwrap_err();
+        assert_eq!(e.to_string(), "line 8, column 13: expected a stack number from 1 to 3, found \"4\"");
+        let e = parse_input(&INPUT.replace("move 3", "mvoe 3")).unwrap_err();
+        assert_eq!((e.line, e.column), (7, 1));
+        assert!(parse_input("[A]\n\nmove 1 from 1 to 1").is_err());
+    }
+
+    #[test]
+    fn test_format_input() {
+        assert_eq!(format_input(&parse_input(INPUT).unwrap().0, &[]), "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n");
+        common::fuzz::check(0..500, |rng| {
+            let count = rng.range(1..=9);
+            let stacks = (0..count).map(|_| {
+                let height = rng.range(0..=6);
+                rng.word("ABCDEFGHIJKLMNOPQRSTUVWXYZ", height).chars().collect()
+            }).collect::<Vec<VecDeque<char>>>();
+            let moves = (0..rng.range(0..=5)).map(|_| {
+                vec![rng.range(0..=99), rng.range(1..=count), rng.range(1..=count)]
+            }).collect::<Vec<_>>();
+            assert_eq!(parse_input(&format_input(&stacks, &moves)).unwrap(), (stacks, moves));
+        });
+    }
+
+    #[test]
+    fn test_generate() {
+        common::fuzz::check(0..50, |rng| {
+            let size = rng.range(0..=50);
+            let ((drawn, moves), tops) = plant(&mut rng.clone(), size);
+            let (stacks, parsed) = parse_input(&generate(rng, size)).unwrap();
+            assert_eq!((&stacks, &parsed), (&drawn, &moves));
+            assert_eq!(puzzle2(&stacks, &moves), tops);
+        });
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use common::{Example, ParseError, Rng, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+    Ok(input.chars().collect())
+}
+
+pub fn puzzle(signal: &[char], window: usize) -> usize {
+    for (i, chs) in signal.windows(window).enumerate() {
+        if chs.iter().copied().collect::<HashSet<_>>().len() == window {
+            return i + window;
+        }
+    }
+    signal.len()
+}
+
+/// A datastream of `size` characters, whose markers both only come at the very end, after at most the
+/// last three letters of a filler of three letters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters = "abcdefghijklmnopqrstuvwxyz".chars().collect::<Vec<_>>();
+    rng.shuffle(&mut letters);
+    let (marker, filler) = letters.split_at(14);
+    let mut signal = (0..size.saturating_sub(14)).map(|_| *rng.choose(&filler[..3])).collect::<String>();
+    signal.extend(marker);
+    signal
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example 1", input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb", answers: [Some("7"), Some("19")] },
+        Example { name: "example 2", input: "bvwbjplbgvbhsrlpgdmjqwftvncz", answers: [Some("5"), Some("23")] },
+        Example { name: "example 3", input: "nppdvjthqldpwncqszvftbrmjlhg", answers: [Some("6"), Some("23")] },
+        Example { name: "example 4", input: "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", answers: [Some("10"), Some("29")] },
+        Example { name: "example 5", input: "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", answers: [Some("11"), Some("26")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle(input, 4)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle(input, 14)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap(), 4), 5);
+        assert_eq!(puzzle(&parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap(), 4), 6);
+        assert_eq!(puzzle(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap(), 4), 10);
+        assert_eq!(puzzle(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap(), 4), 11);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle(&parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(), 14), 19);
+        assert_eq!(puzzle(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap(), 14), 23);
+        assert_eq!(puzzle(&parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap(), 14), 23);
+        assert_eq!(puzzle(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap(), 14), 29);
+        assert_eq!(puzzle(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap(), 14), 26);
+    }
+
+    #[test]
+    fn test_generate() {
+        common::fuzz::check(0..50, |rng| {
+            let size = rng.range(14..=200);
+            let signal = parse(&generate(rng, size)).unwrap();
+            // the filler repeats three letters, so a marker can only take in its last three
+            assert!(puzzle(&signal, 4) > size - 14);
+            assert!(puzzle(&signal, 14) > size - 4);
+        });
+    }
+}
@@ -0,0 +1,261 @@
+use std::cell::Cell;
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug, Clone)]
+pub struct File {
+    kind: Kind,
+    path: String,
+    size: Cell<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Dir,
+    File,
+}
+
+impl File {
+    fn new(kind: Kind, path: &str, size: u32) -> Self {
+        Self { kind, path: path.to_string(), size: Cell::new(size) }
+    }
+}
+
+fn get_parent(abspath: &str) -> Option<String> {
+    if abspath == "/" {
+        return None;
+    }
+
+    abspath.rsplit_once('/').map(|(p, _)| {
+        if p.is_empty() {
+            "/".to_string()
+        } else {
+            p.to_string()
+        }
+    })
+}
+
+fn get_abspath(dir: &str, name: &str) -> String {
+    dir.trim_end_matches('/').to_string() + "/" + name
+}
+
+type Tree = (HashMap<String, File>, HashMap<String, HashSet<String>>);
+
+/// Checks the name of a file or directory within its directory, which would otherwise make it part of
+/// another directory, or of itself.
+fn check_name<'a>(src: &Source, name: &'a str) -> Result<&'a str, ParseError> {
+    if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+        return Err(src.error(name, "a file name"));
+    }
+    Ok(name)
+}
+
+fn build(input: &str) -> Result<Tree, ParseError> {
+    let src = Source::new(input);
+    let mut files = HashMap::new();
+    let mut children = HashMap::new();
+    let mut dir = "".to_string();
+    for line in input.lines() {
+        if line.starts_with("$ cd ") { // command cd
+            let name = line.trim_start_matches("$ cd ");
+            if dir.is_empty() || name == "/" {
+                dir = name.to_string();
+            } else if name == ".." {
+                dir = get_parent(&dir).ok_or_else(|| src.error(name, "a directory name, / has no parent"))?;
+            } else {
+                dir = get_abspath(&dir, check_name(&src, name)?);
+            }
+        } else if line.starts_with("$ ls") { // command ls
+            // do nothing
+        } else if line.starts_with("dir ") { // dir
+            let name = check_name(&src, line.trim_start_matches("dir "))?;
+            let abspath = get_abspath(&dir, name);
+            let file = File::new(Kind::Dir, &abspath, 0);
+            files.insert(abspath.clone(), file);
+            children.entry(dir.clone()).or_insert(HashSet::new()).insert(abspath.clone());
+        } else { // file
+            common::trace!("line: {:?}", line);
+            let mut split = line.split(' ');
+            let size = src.parse::<u32>(src.next(&mut split, line, "a file size")?, "a command, directory or file size")?;
+            let name = check_name(&src, src.next(&mut split, line, "a file name")?)?;
+            let abspath = get_abspath(&dir, name);
+            let file = File::new(Kind::File, &abspath, size);
+            files.insert(abspath.clone(), file);
+            children.entry(dir.clone()).or_insert(HashSet::new()).insert(abspath.clone());
+        }
+    }
+    files.insert("/".to_string(), File::new(Kind::Dir, "/", 0));
+    Ok((files, children))
+}
+
+fn calculate_size(path: &str, files: &HashMap<String, File>, children: &HashMap<String, HashSet<String>>) -> u32 {
+    let file = files.get(path).unwrap();
+    match file.kind {
+        Kind::Dir => {
+            if file.size.get() == 0 {
+                let mut sum = 0;
+                for child in children.get(&file.path).into_iter().flatten() { // unlisted dirs count as empty
+                    sum += calculate_size(child, files, children);
+                }
+                file.size.set(sum);
+            }
+            file.size.get()
+        }
+        Kind::File => file.size.get(),
+    }
+}
+
+/// Builds the file tree and fills in the total size of every directory.
+pub fn parse(input: &str) -> Result<HashMap<String, File>, ParseError> {
+    let (files, children) = build(input)?;
+    let _ = calculate_size("/", &files, &children);
+    Ok(files)
+}
+
+pub fn puzzle1(files: &HashMap<String, File>) -> u32 {
+    files.values()
+        .filter(|f| f.kind == Kind::Dir && f.size.get() < 100000)
+        .map(|f| f.size.get())
+        .sum()
+}
+
+pub fn puzzle2(files: &HashMap<String, File>) -> u32 {
+    let available = 70000000 - files.get("/").unwrap().size.get();
+    let freeup = max(30000000 - available, 0);
+    files.values()
+        .filter(|f| f.kind == Kind::Dir && f.size.get() >= freeup)
+        .map(|f| f.size.get()).min().unwrap()
+}
+
+/// A terminal session exploring `size` directories, each new one usually inside the latest, so the
+/// tree gets deep, and filling between 40000000 and 70000000 of the disk.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut children = vec![vec![]; size + 1];
+    for dir in 1..=size {
+        let parent = if rng.chance(0.5) { dir - 1 } else { rng.range(0..=dir - 1) };
+        children[parent].push(dir);
+    }
+    let file_counts = (0..=size).map(|_| rng.range(0..=4)).collect::<Vec<_>>();
+    let max_file = 20000000 / file_counts.iter().sum::<usize>().max(1);
+    let mut files = file_counts.iter().map(|&n| {
+        (0..n).map(|_| rng.range(1..=max_file)).collect::<Vec<_>>()
+    }).collect::<Vec<_>>();
+    // one file in / tops the disk up to the total
+    let total = files.iter().flatten().sum::<usize>();
+    files[0].push(rng.range(40000001..=70000000) - total);
+
+    // names only have to differ from the other entries of the same directory
+    let mut names = vec!["/".to_string(); size + 1];
+    let mut listings = vec![vec![]; size + 1];
+    for dir in 0..=size {
+        let mut taken = HashSet::new();
+        let mut name = |rng: &mut Rng, ext: &str| loop {
+            let len = rng.range(1..=8);
+            let name = rng.word("abcdefghijklmnopqrstuvwxyz", len) + ext;
+            if taken.insert(name.clone()) {
+                break name;
+            }
+        };
+        for &child in &children[dir] {
+            names[child] = name(rng, "");
+            listings[dir].push(format!("dir {}", names[child]));
+        }
+        for &bytes in &files[dir] {
+            let ext = *rng.choose(&["", ".txt", ".dat", ".log"]);
+            listings[dir].push(format!("{} {}", bytes, name(rng, ext)));
+        }
+        rng.shuffle(&mut listings[dir]);
+    }
+
+    // depth first without recursion, `None` standing for the `cd ..` out of a directory
+    let mut input = String::new();
+    let mut stack = vec![Some(0)];
+    while let Some(dir) = stack.pop() {
+        let Some(dir) = dir else {
+            input += "$ cd ..\n";
+            continue;
+        };
+        input += &format!("$ cd {}\n$ ls\n", names[dir]);
+        listings[dir].iter().for_each(|entry| input += &format!("{}\n", entry));
+        for &child in children[dir].iter().rev() {
+            stack.extend([None, Some(child)]);
+        }
+    }
+    input
+}
+
+const INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = HashMap<String, File>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("95437"), Some("24933642")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 95437);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 24933642);
+    }
+
+    #[test]
+    fn test_nameless_directory() {
+        // a nameless directory would be its own child
+        assert!(parse("$ cd /\n$ ls\ndir \n$ cd \n").is_err());
+    }
+}
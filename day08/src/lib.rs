@@ -0,0 +1,103 @@
+use common::{Example, ParseError, Rng, Solution, Source};
+use grid::{Grid, Pos};
+
+pub fn parse_input(input: &str) -> Result<Grid<i8>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(Source::new(input).missing(input, "a row of trees"));
+    }
+    Grid::parse(input, "a tree height", |c| c.to_digit(10).map(|d| d as i8))
+}
+
+pub fn puzzle1(grid: &Grid<i8>) -> u32 {
+    grid.positions().filter(|&pos| is_visible(grid, pos)).count() as u32
+}
+
+fn is_visible(grid: &Grid<i8>, (row, col): Pos) -> bool {
+    let cur = grid[(row, col)];
+    let line = grid.row(row);
+    grid.column(col).take(row).all(|&height| cur > height)
+        || grid.column(col).skip(row + 1).all(|&height| cur > height)
+        || line[..col].iter().all(|&height| cur > height)
+        || line[col + 1..].iter().all(|&height| cur > height)
+}
+
+pub fn puzzle2(grid: &Grid<i8>) -> u32 {
+    grid.positions().map(|pos| score(grid, pos)).max().unwrap_or(0)
+}
+
+fn score(grid: &Grid<i8>, (row, col): Pos) -> u32 {
+    let cur = grid[(row, col)];
+    let line = grid.row(row);
+    let up = viewing_distance(cur, grid.column(col).take(row).rev());
+    let down = viewing_distance(cur, grid.column(col).skip(row + 1));
+    let left = viewing_distance(cur, line[..col].iter().rev());
+    let right = viewing_distance(cur, line[col + 1..].iter());
+    up * down * left * right
+}
+
+/// Counts the trees in view, up to and including the first one at least as tall as `cur`.
+fn viewing_distance<'a>(cur: i8, trees: impl Iterator<Item = &'a i8>) -> u32 {
+    let mut distance = 0;
+    for &height in trees {
+        distance += 1;
+        if cur <= height {
+            break;
+        }
+    }
+    distance
+}
+
+/// A square forest `size` trees wide.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| rng.word("0123456789", size.max(1)) + "\n").collect()
+}
+
+const INPUT: &str = "30373
+25512
+65332
+33549
+35390";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Grid<i8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("21"), Some("8")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse_input(INPUT).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse_input(INPUT).unwrap()), 8);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+/// A motion of the head: a unit direction and the number of steps.
+pub type Motion = ((i32, i32), u8);
+
+/// Parses one motion per line.
+pub fn parse(input: &str) -> Result<Vec<Motion>, ParseError> {
+    let src = Source::new(input);
+    input.lines().map(|line| {
+        let mut split = line.split(' ');
+        let mov = src.next(&mut split, line, "R, L, U or D")?;
+        let size = src.parse::<u8>(src.next(&mut split, line, "a step count")?, "a step count")?;
+        let dir = match mov {
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            "U" => (0, 1),
+            "D" => (0, -1),
+            _ => return Err(src.error(mov, "R, L, U or D")),
+        };
+        Ok((dir, size))
+    }).collect()
+}
+
+pub fn puzzle1(motions: &[Motion]) -> usize {
+    let mut head = (0, 0);
+    let mut tail = (0, 0);
+    motions.iter().flat_map(|&(dir, size)| {
+        (0..size).map(|_| {
+            head = (head.0 + dir.0, head.1 + dir.1); // new head
+            tail = move_tail(head, tail);
+            tail
+        }).collect::<Vec<_>>()
+    }).collect::<HashSet<_>>().len()
+}
+
+pub fn puzzle2(motions: &[Motion], initial: (i32, i32)) -> usize {
+    let mut heads = [initial; 10];
+    let tails = motions.iter().flat_map(|&(dir, size)| {
+        (0..size).map(|_| {
+            heads[0] = (heads[0].0 + dir.0, heads[0].1 + dir.1); // new head
+            for i in 1..heads.len() {
+                heads[i] = move_tail(heads[i - 1], heads[i]);
+            }
+            heads[heads.len() - 1] // tail
+        }).collect::<Vec<_>>()
+    }).collect::<HashSet<_>>();
+    tails.len()
+}
+
+fn move_tail(head: (i32, i32), tail: (i32, i32)) -> (i32, i32) {
+    if i32::abs(head.0 - tail.0) <= 1 && i32::abs(head.1 - tail.1) <= 1 { // adjacent
+        return tail;
+    }
+
+    // dir_tail -> dir_heads
+    let mut m = HashMap::new();
+    m.insert((-1, 1), vec![(-2, 1), (-1, 2), (-2, 2)]);
+    m.insert((0, 1), vec![(0, 2)]);
+    m.insert((1, 1), vec![(1, 2), (2, 1), (2, 2)]);
+    m.insert((1, 0), vec![(2, 0)]);
+    m.insert((1, -1), vec![(2, -1), (1, -2), (2, -2)]);
+    m.insert((0, -1), vec![(0, -2)]);
+    m.insert((-1, -1), vec![(-1, -2), (-2, -1), (-2, -2)]);
+    m.insert((-1, 0), vec![(-2, 0)]);
+
+    for (dir_tail, dir_heads) in m {
+        for dir_head in dir_heads {
+            if tail.0 + dir_head.0 == head.0 && tail.1 + dir_head.1 == head.1 {
+                return (tail.0 + dir_tail.0, tail.1 + dir_tail.1);
+            }
+        }
+    }
+    unreachable!()
+}
+
+
+/// `size` motions of 1 to 20 steps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{} {}\n", rng.choose(&["R", "L", "U", "D"]), rng.range(1..=20))).collect()
+}
+
+const INPUT1: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+const INPUT2: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Motion>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT1, answers: [Some("13"), Some("1")] },
+        Example { name: "larger example", input: INPUT2, answers: [None, Some("36")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input, (0, 0))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT1).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT1).unwrap(), (0, 0)), 1);
+        assert_eq!(puzzle2(&parse(INPUT2).unwrap(), (15, 11)), 36);
+    }
+}
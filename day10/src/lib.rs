@@ -0,0 +1,91 @@
+use common::{Example, ParseError, Rng, Solution, Source};
+
+/// Expands the program into the value added to X at the end of every cycle.
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let src = Source::new(input);
+    let mut cycles = vec![];
+    for line in input.lines() {
+        if line == "noop" {
+            cycles.push(0);
+        } else {
+            let incr = src.prefix(line, "addx ").map_err(|_| src.error(line, "noop or addx"))?;
+            cycles.extend([0, src.parse::<i32>(incr, "a number")?]);
+        }
+    }
+    Ok(cycles)
+}
+
+pub fn puzzle1(cycles: &[i32]) -> i32 {
+    let mut x = 1;
+    let mut cycle = 0;
+    cycles.iter().flat_map(|&incr| {
+        cycle += 1;
+        let res = if cycle == 20 || cycle == 60 || cycle == 100 || cycle == 140 || cycle == 180 || cycle == 220 {
+            Some(cycle * x)
+        } else {
+            None
+        };
+        x += incr;
+        res
+    }).sum()
+}
+
+pub fn puzzle2(cycles: &[i32]) -> String {
+    let mut pos = 1;
+    let mut crt = 0;
+    let chs: Vec<_> = cycles.iter().map(|&incr| {
+        let draw = if pos - 1 <= crt && crt <= pos + 1 { '#' } else { '.' };
+        pos += incr;
+        crt = (crt + 1) % 40;
+        draw
+    }).collect();
+    chs.chunks(40).map(String::from_iter).collect::<Vec<_>>().join("\n")
+}
+
+/// A program of `size` instructions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        if rng.chance(0.3) { "noop\n".to_string() } else { format!("addx {}\n", rng.range(-20..=20)) }
+    }).collect()
+}
+
+const INPUT: &str = include_str!("../demo.txt");
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("13140"), Some("##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 13140);
+    }
+}
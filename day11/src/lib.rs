@@ -0,0 +1,265 @@
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+pub struct Monkey {
+    id: usize,
+    items: Cell<VecDeque<u64>>,
+    operation: Operation,
+    divisor: u64,
+    // false: index 0, true: index 1
+    next: [usize; 2],
+    inspected: Cell<u64>,
+}
+
+#[derive(Clone)]
+struct Operation {
+    op: Rc<dyn Fn(u64) -> u64>,
+}
+
+impl Clone for Monkey {
+    fn clone(&self) -> Self {
+        let items = self.items.take();
+        self.items.replace(items.clone());
+        Monkey {
+            id: self.id,
+            items: Cell::new(items),
+            operation: self.operation.clone(),
+            divisor: self.divisor,
+            next: self.next,
+            inspected: self.inspected.clone(),
+        }
+    }
+}
+
+impl Monkey {
+    fn inspect(&self) -> Option<u64> {
+        let mut deque = self.items.take();
+        let item = deque.pop_front()?;
+        self.items.replace(deque);
+        self.inspected.replace(self.inspected.take() + 1);
+        Some(item)
+    }
+
+    fn operate(&self, item: u64) -> u64 {
+        (self.operation.op)(item)
+    }
+
+    fn next(&self, item: u64) -> usize {
+        self.next[item.is_multiple_of(self.divisor) as usize]
+    }
+
+    fn receive(&self, item: u64) {
+        let mut deque = self.items.take();
+        deque.push_back(item);
+        self.items.replace(deque);
+    }
+}
+
+pub fn puzzle1(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let lookup: HashMap<_, _> = monkeys.iter().map(|m| (m.id, m)).collect();
+    for _ in 0..20 {
+        for m in &monkeys {
+            while let Some(item) = m.inspect() {
+                let item = m.operate(item) / 3; // worry level
+                lookup.get(&m.next(item)).unwrap().receive(item);
+            }
+        }
+    }
+    monkeys.sort_unstable_by(|m1, m2| m2.inspected.cmp(&m1.inspected));
+    monkeys[0].inspected.take() * monkeys[1].inspected.take()
+}
+
+pub fn puzzle2(monkeys: &[Monkey]) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let common_multiple = monkeys.iter().map(|m| m.divisor).product::<u64>();
+    let lookup: HashMap<_, _> = monkeys.iter().map(|m| (m.id, m)).collect();
+    for _ in 0..10000 {
+        for m in &monkeys {
+            while let Some(item) = m.inspect() {
+                let item = m.operate(item) % common_multiple;
+                let to = m.next(item);
+                lookup.get(&to).unwrap().receive(item);
+            }
+        }
+        for m in &monkeys {
+            let items = m.items.take();
+            m.items.replace(items);
+        }
+    }
+    monkeys.sort_unstable_by(|m1, m2| m2.inspected.cmp(&m1.inspected));
+    monkeys[0].inspected.take() * monkeys[1].inspected.take()
+}
+
+pub fn build(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let src = Source::new(input);
+    let mut targets = vec![];
+    let monkeys = input.split("\n\n").map(|block| {
+        let mut lines = block.lines();
+        let mut line = |expected: &str| src.next(&mut lines, &block[block.len()..], expected);
+        let id = src.prefix(line("a monkey id")?, "Monkey ")?;
+        let id = src.parse::<usize>(id.strip_suffix(':').unwrap_or(id), "a monkey id")?;
+        let items = src.prefix(line("starting items")?, "  Starting items: ")?;
+        let items = items.split(", ").filter(|s| !s.is_empty()).map(|s| src.parse::<u64>(s, "a worry level"))
+            .collect::<Result<VecDeque<_>, _>>()?;
+        let ops = src.prefix(line("an operation")?, "  Operation: new = ")?;
+        let mut tokens = ops.split(' ');
+        let old = src.next(&mut tokens, ops, "\"old\"")?;
+        if old != "old" {
+            return Err(src.error(old, "\"old\""));
+        }
+        let operator = src.next(&mut tokens, ops, "an operator")?;
+        let operand = src.next(&mut tokens, ops, "\"old\" or a number")?;
+        let operation = match operand {
+            "old" => match operator {
+                "+" => Operation { op: Rc::new(|old| old + old) },
+                "-" => Operation { op: Rc::new(|_| 0) },
+                "*" => Operation { op: Rc::new(|old| old * old) },
+                "/" => Operation { op: Rc::new(|_| 1) },
+                _ => return Err(src.error(operator, "+, -, * or /")),
+            }
+            token => {
+                let operand = src.parse::<u64>(token, "\"old\" or a number")?;
+                match operator {
+                    "+" => Operation { op: Rc::new(move |old| old + operand) },
+                    "-" => Operation { op: Rc::new(move |old| old - operand) },
+                    "*" => Operation { op: Rc::new(move |old| old * operand) },
+                    "/" if operand == 0 => return Err(src.error(token, "a non-zero divisor")),
+                    "/" => Operation { op: Rc::new(move |old| old / operand) },
+                    _ => return Err(src.error(operator, "+, -, * or /")),
+                }
+            }
+        };
+        let divisor = src.prefix(line("a test")?, "  Test: divisible by ")?;
+        let divisor = match src.parse::<u64>(divisor, "a non-zero divisor")? {
+            0 => return Err(src.error(divisor, "a non-zero divisor")),
+            n => n,
+        };
+        let mut throw_to = [0; 2];
+        for (i, prefix) in [(1, "    If true: throw to monkey "), (0, "    If false: throw to monkey ")] {
+            let target = src.prefix(line("a throw target")?, prefix)?;
+            throw_to[i] = src.parse::<usize>(target, "a monkey id")?;
+            targets.push((target, throw_to[i]));
+        }
+        Ok(Monkey { id, items: Cell::new(items), operation, divisor, next: throw_to, inspected: Cell::new(0) })
+    }).collect::<Result<Vec<_>, _>>()?;
+    // throws may go forward, so targets can only be checked once every monkey is known
+    for (token, target) in targets {
+        if !monkeys.iter().any(|m| m.id == target) {
+            return Err(src.error(token, "an existing monkey id"));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// `size` monkeys with distinct prime divisors: monkey 0 squares the worry level, monkey 1 multiplies
+/// it and the others add to it. Nobody throws to monkey 0, so that part 1 squares every item at most
+/// once and its worry levels stay far from overflowing.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [u64; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+    let size = size.max(3);
+    let mut divisors = PRIMES.to_vec();
+    rng.shuffle(&mut divisors);
+    (0..size).map(|id| {
+        let items = (0..rng.range(1..=6)).map(|_| rng.range(50..=99).to_string()).collect::<Vec<_>>();
+        let operation = match id {
+            0 => "old * old".to_string(),
+            1 => format!("old * {}", rng.range(2..=19)),
+            _ => format!("old + {}", rng.range(1..=8)),
+        };
+        let mut target = || loop {
+            let target = rng.range(1..=size - 1);
+            if target != id {
+                break target;
+            }
+        };
+        let (if_true, if_false) = (target(), target());
+        format!("Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    \
+                 If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                id, items.join(", "), operation, divisors[id % divisors.len()], if_true, if_false)
+    }).collect::<Vec<_>>().join("\n")
+}
+
+const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("10605"), Some("2713310158")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&build(INPUT).unwrap()), 10605);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&build(INPUT).unwrap()), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = build(&INPUT.replace("old + 6", "old % 6")).err().unwrap();
+        assert_eq!(e.to_string(), "line 10, column 24: expected +, -, * or /, found \"%\"");
+        let e = build(&INPUT.replace("throw to monkey 3", "throw to monkey 4")).err().unwrap();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (6, 31, "an existing monkey id"));
+        assert!(build(&INPUT.replace("divisible by 13", "divisible by 0")).is_err());
+    }
+}
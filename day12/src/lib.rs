@@ -0,0 +1,119 @@
+use common::{search, Example, ParseError, Rng, Solution, Source};
+use grid::{Grid, Pos};
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    let src = Source::new(input);
+    let heights = Grid::parse(input, "a-z, S or E", |c| (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c))?;
+    for marker in ['S', 'E'] {
+        if heights.find(&marker).is_none() {
+            return Err(src.missing(input.trim_end(), format!("{:?} somewhere in the heightmap", marker)));
+        }
+    }
+    Ok(heights)
+}
+
+pub fn puzzle1(heights: &Grid<char>) -> usize {
+    let start = heights.find(&'S').unwrap();
+    climb(heights, [start])
+}
+
+pub fn puzzle2(heights: &Grid<char>) -> usize {
+    let start = heights.find(&'S').into_iter().chain(heights.find_all(&'a'));
+    climb(heights, start)
+}
+
+/// The fewest steps from the closest of `start` to `E`, or `usize::MAX` if it cannot be reached.
+fn climb(heights: &Grid<char>, start: impl IntoIterator<Item = Pos>) -> usize {
+    let elevation = |pos: Pos| match heights[pos] {
+        'S' => 'a',
+        'E' => 'z',
+        c => c,
+    } as i8;
+    let next = |&pos: &Pos| heights.neighbors4(pos).filter(move |&next| elevation(next) - elevation(pos) <= 1);
+    search::bfs_multi(start, next, |&pos| heights[pos] == 'E').map_or(usize::MAX, |(steps, _)| steps)
+}
+
+/// A heightmap `size` squares wide and a quarter as high, climbing from `S` on the left edge to `E` on
+/// the right one along a path that is always walkable.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(26), (size / 4).max(1));
+    let (start, end) = ((rng.range(0..=height - 1), 0), (rng.range(0..=height - 1), width - 1));
+    let distance = |(r, c): Pos| r.abs_diff(end.0) + c.abs_diff(end.1);
+    // elevation grows by at most one per step towards E, from a at S to z at E
+    let scale = (distance(start) / 25).max(1);
+    let mut heights = Grid::new(width, height, 'a');
+    for pos in heights.positions().collect::<Vec<_>>() {
+        heights[pos] = char::from(b'z' - (distance(pos) / scale).min(25) as u8);
+    }
+    // raise random squares into cliffs, but not on one walk straight towards E
+    let mut path = Grid::new(width, height, false);
+    let mut pos = start;
+    path[pos] = true;
+    while pos != end {
+        let vertical = pos.0 != end.0 && (pos.1 == end.1 || rng.chance(0.5));
+        pos = match vertical {
+            true if pos.0 < end.0 => (pos.0 + 1, pos.1),
+            true => (pos.0 - 1, pos.1),
+            false => (pos.0, pos.1 + 1),
+        };
+        path[pos] = true;
+    }
+    for pos in heights.positions().collect::<Vec<_>>() {
+        if !path[pos] && rng.chance(0.2) && heights[pos] < 'y' {
+            heights[pos] = char::from(heights[pos] as u8 + 2);
+        }
+    }
+    heights[start] = 'S';
+    heights[end] = 'E';
+    heights.to_string() + "\n"
+}
+
+const INPUT: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("31"), Some("29")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 31);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 29);
+    }
+}
@@ -0,0 +1,170 @@
+// ref: https://github.com/AxlLind/AdventOfCode2022/blob/main/src/bin/13.rs
+
+use std::cmp::{max, Ordering};
+
+use common::{Example, ParseError, Rng, Solution, Source};
+use serde_json::Value;
+
+pub fn parse(input: &str) -> Result<Vec<Value>, ParseError> {
+    let src = Source::new(input);
+    let signals = input.lines().filter(|l| !l.is_empty()).map(|l| {
+        let value = serde_json::from_str::<Value>(l).map_err(|e| {
+            let at = e.column().saturating_sub(1).min(l.len());
+            let at = (0..=at).rev().find(|&i| l.is_char_boundary(i)).unwrap();
+            src.error(&l[at..], "a packet of lists and integers")
+        })?;
+        if !is_packet(&value) {
+            return Err(src.error(l, "a packet of lists and integers"));
+        }
+        Ok(value)
+    }).collect::<Result<Vec<_>, _>>()?;
+    if signals.len() % 2 != 0 {
+        return Err(src.missing(input.trim_end(), "the second packet of the pair"));
+    }
+    Ok(signals)
+}
+
+fn is_packet(value: &Value) -> bool {
+    match value {
+        Value::Number(n) => n.is_u64(),
+        Value::Array(a) => a.iter().all(is_packet),
+        _ => false,
+    }
+}
+
+pub fn puzzle1(signals: &[Value]) -> usize {
+    signals.chunks(2).enumerate().map(|(i, ck)| {
+        match cmp(&ck[0], &ck[1]) {
+            Ordering::Greater => 0,
+            _ => i + 1,
+        }
+    }).sum()
+}
+
+pub fn puzzle2(signals: &[Value]) -> usize {
+    let mut signals = signals.to_vec();
+    let dividers = [
+        serde_json::from_str::<Value>("[[2]]").unwrap(),
+        serde_json::from_str::<Value>("[[6]]").unwrap(),
+    ];
+    signals.extend(dividers.iter().cloned());
+    signals.sort_unstable_by(cmp);
+    signals.iter().enumerate().map(|(i, v)| { if dividers.contains(v) { i + 1 } else { 1 } })
+        .product()
+}
+
+fn cmp(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_u64().unwrap().cmp(&y.as_u64().unwrap()),
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..max(a.len(), b.len()) {
+                match (a.get(i), b.get(i)) {
+                    (None, _) => return Ordering::Less,
+                    (_, None) => return Ordering::Greater,
+                    (Some(x), Some(y)) => match cmp(x, y) {
+                        Ordering::Equal => {} // continue
+                        c => return c,
+                    }
+                }
+            }
+            Ordering::Equal
+        }
+        (Value::Array(_), Value::Number(_)) => cmp(a, &Value::Array(vec![b.clone()])),
+        (Value::Number(_), Value::Array(_)) => cmp(&Value::Array(vec![a.clone()]), b),
+        _ => unreachable!(),
+    }
+}
+
+/// `size` pairs of packets nested up to four lists deep.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn list(rng: &mut Rng, depth: usize) -> String {
+        let items = (0..rng.range(0..=5)).map(|_| {
+            if depth == 0 || rng.chance(0.7) { rng.range(0..=10).to_string() } else { list(rng, depth - 1) }
+        }).collect::<Vec<_>>();
+        format!("[{}]", items.join(","))
+    }
+    (0..size).map(|_| format!("{}\n{}\n", list(rng, 4), list(rng, 4))).collect::<Vec<_>>().join("\n")
+}
+
+const INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Value>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("13"), Some("140")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 140);
+    }
+
+    #[test]
+    fn test_packet_round_trip() {
+        common::fuzz::check(0..200, |rng| {
+            let input = generate(rng, 5);
+            let packets = parse(&input).unwrap().iter().map(Value::to_string).collect::<Vec<_>>();
+            assert_eq!(packets, input.lines().filter(|l| !l.is_empty()).collect::<Vec<_>>());
+        });
+    }
+
+    #[test]
+    fn test_error_column() {
+        // serde_json counts bytes, the error characters
+        assert_eq!(parse("[1,é]\n[2]").unwrap_err().column, 4);
+    }
+}
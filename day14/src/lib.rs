@@ -0,0 +1,162 @@
+use std::cmp::{max, min};
+
+use common::image::{self, BLACK, GOLD, WHITE};
+use common::{Example, ParseError, Rng, Solution, Source};
+use grid::Grid;
+
+/// How many units of sand come to rest between two frames of a recording.
+const FRAME_EVERY: usize = 50;
+
+pub fn puzzle1(map: &Grid<bool>, bottom: usize) -> usize {
+    let rock = map;
+    let mut map = map.clone();
+    let mut units: usize = 0;
+    for _ in 0.. {
+        let Some((x, y)) = find(&map, bottom + 2) else { break; };
+        if y >= bottom {
+            break;
+        }
+        map[(y, x)] = true;
+        units += 1;
+        if units.is_multiple_of(FRAME_EVERY) {
+            frame("day14-part1", rock, &map, bottom);
+        }
+    }
+    frame("day14-part1", rock, &map, bottom);
+    units
+}
+
+pub fn puzzle2(map: &Grid<bool>, bottom: usize) -> usize {
+    let rock = map;
+    let mut map = map.clone();
+    let mut units: usize = 0;
+    for _ in 0.. {
+        let Some((x, y)) = find(&map, bottom + 2) else { break; };
+        map[(y, x)] = true;
+        units += 1;
+        if units.is_multiple_of(FRAME_EVERY) {
+            frame("day14-part2", rock, &map, bottom);
+        }
+    }
+    frame("day14-part2", rock, &map, bottom);
+    units
+}
+
+/// Records the part of the cave the sand can reach, with the floor of part 2 under it.
+fn frame(name: &str, rock: &Grid<bool>, map: &Grid<bool>, bottom: usize) {
+    if !image::recording() {
+        return;
+    }
+    let floor = bottom + 2;
+    image::frame(name, 2 * floor + 1, floor + 1, |(y, x)| {
+        let pos = (y, 500 - floor + x);
+        if y == floor || rock[pos] {
+            WHITE
+        } else if map[pos] {
+            GOLD
+        } else {
+            BLACK
+        }
+    });
+}
+
+fn find(map: &Grid<bool>, floor: usize) -> Option<(usize, usize)> {
+    let (mut x, mut y): (usize, usize) = (500, 0);
+    while y + 1 < floor {
+        let Some(below) = [0, -1, 1].into_iter().find_map(|dx| map.step((y, x), (1, dx)).filter(|&p| !map[p])) else { break; };
+        (y, x) = below;
+    }
+    if !map[(y, x)] { Some((x, y)) } else { None }
+}
+
+pub fn parse(input: &str) -> Result<(Grid<bool>, usize), ParseError> {
+    let src = Source::new(input);
+    let mut map = Grid::new(1000, 1000, false); // map[(y, x)]
+    let mut bottom = 0;
+    // the sand spreads up to floor distance sideways from x=500, so the floor has to stay inside the map
+    let coordinate = |s: &str, limit: usize| match s.parse::<usize>() {
+        Ok(n) if n < limit => Ok(n),
+        _ => Err(src.error(s, format!("a coordinate below {}", limit))),
+    };
+    for l in input.lines() {
+        let coordinates = l.split(" -> ").map(|p| {
+            let (x, y) = src.split_once(p, ",")?;
+            Ok((coordinate(x, 1000)?, coordinate(y, 498)?))
+        }).collect::<Result<Vec<_>, _>>()?;
+        coordinates.windows(2).for_each(|w| {
+            let (x1, y1) = w[0];
+            let (x2, y2) = w[1];
+            for y in min(y1, y2)..=max(y1, y2) {
+                map.row_mut(y)[min(x1, x2)..=max(x1, x2)].fill(true);
+            }
+            bottom = max(bottom, max(y1, y2));
+        })
+    }
+    Ok((map, bottom))
+}
+
+/// `size` rock paths of up to five points around the sand source.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let mut point = (rng.range(440..=560), rng.range(13..=170));
+        let mut points = vec![point];
+        for i in 0..rng.range(1..=4) {
+            let step = rng.range(-10..=10i64);
+            point = match i % 2 {
+                0 => ((point.0 as i64 + step).clamp(0, 999) as usize, point.1),
+                _ => (point.0, (point.1 as i64 + step).clamp(1, 497) as usize),
+            };
+            points.push(point);
+        }
+        points.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<_>>().join(" -> ") + "\n"
+    }).collect()
+}
+
+const INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = (Grid<bool>, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("24"), Some("93")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(&input.0, input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(&input.0, input.1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        let (map, bottom) = parse(INPUT).unwrap();
+        assert_eq!(puzzle1(&map, bottom), 24);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        let (map, bottom) = parse(INPUT).unwrap();
+        assert_eq!(puzzle2(&map, bottom), 93);
+    }
+}
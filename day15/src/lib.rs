@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use common::interval::Interval;
+use common::{Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sensor {
+    sx: i64,
+    sy: i64,
+    bx: i64,
+    by: i64,
+    md: i64,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let src = Source::new(input);
+    input.lines().map(|l| {
+        let (sx, left) = src.split_once(src.prefix(l, "Sensor at x=")?, ", y=")?;
+        let (sy, left) = src.split_once(left, ": closest beacon is at x=")?;
+        let (bx, by) = src.split_once(left, ", y=")?;
+        let [sx, sy, bx, by] = [sx, sy, bx, by].map(|n| src.parse::<i64>(n, "a coordinate"));
+        let (sx, sy, bx, by) = (sx?, sy?, bx?, by?);
+        let md = (sx - bx).abs() + (sy - by).abs();
+        Ok(Sensor { sx, sy, bx, by, md })
+    }).collect()
+}
+
+/// The columns of row `y` within range of some sensor.
+fn calculate_coverage(sensors: &[Sensor], y: i64) -> Vec<Interval<i64>> {
+    Interval::merge(sensors.iter().map(|s| {
+        let left = s.md - (s.sy - y).abs();
+        Interval::new(s.sx - left, s.sx + left)
+    }))
+}
+
+pub fn puzzle1(sensors: &[Sensor], y: i64) -> i64 {
+    let covered_ranges = calculate_coverage(sensors, y);
+    let beacons = sensors.iter().filter(|s| s.by == y).map(|s| (s.bx, s.by)).collect::<HashSet<_>>().len();
+    covered_ranges.iter().map(|r| r.len() as i64).sum::<i64>() - beacons as i64
+}
+
+pub fn puzzle2(sensors: &[Sensor], upper_bound: i64) -> i64 {
+    for s in sensors {
+        for (dx, dy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+            for distance in 0..=(s.md + 1) {
+                let bx = s.sx + dx * distance;
+                let by = s.sy + dy * (s.md + 1 - distance);
+                if bx < 0 || bx >= upper_bound || by < 0 || by >= upper_bound {
+                    continue;
+                }
+                if sensors.iter().all(|s| (s.sx - bx).abs() + (s.sy - by).abs() > s.md) {
+                    return bx * 4000000 + by;
+                }
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// The example scans row 10 and a 20x20 area, the real input row 2000000 and a 4000000x4000000 area.
+fn search_area(sensors: &[Sensor]) -> (i64, i64) {
+    if sensors.iter().all(|s| s.sx <= 100 && s.sy <= 100) {
+        (10, 20)
+    } else {
+        (2000000, 4000000)
+    }
+}
+
+/// `size` sensors around a distress beacon, plus four far away ones that cover everything but the
+/// beacon in the 4000000x4000000 search area.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    plant(rng, size).0
+}
+
+/// The sensors [`generate`] places, with the tuning frequency of the beacon they leave out.
+fn plant(rng: &mut Rng, size: usize) -> (String, i64) {
+    let (px, py) = (rng.range(0..=3999999i64), rng.range(0..=3999999i64));
+    // a sensor diagonally `d` away from the beacon with a range of 2d - 1 covers its whole quadrant but the beacon
+    let d = rng.range(5000000..=6000000);
+    let mut sensors = [(-1, -1), (-1, 1), (1, -1), (1, 1)].map(|(dx, dy)| {
+        let (sx, sy) = (px + dx * d, py + dy * d);
+        (sx, sy, sx - dx * (2 * d - 1), sy)
+    }).to_vec();
+    while sensors.len() < size + 4 {
+        let (sx, sy) = (rng.range(0..=3999999i64), rng.range(0..=3999999i64));
+        let distance = (sx - px).abs() + (sy - py).abs();
+        if distance < 2 {
+            continue;
+        }
+        let range = rng.range(1..=(distance - 1).min(500000));
+        let dx = rng.range(-range..=range);
+        let dy = (range - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensors.push((sx, sy, sx + dx, sy + dy));
+    }
+    rng.shuffle(&mut sensors);
+    let input = sensors.iter().map(|(sx, sy, bx, by)| {
+        format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sx, sy, bx, by)
+    }).collect();
+    (input, px * 4000000 + py)
+}
+
+const INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Sensor>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("26"), Some("56000011")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        puzzle1(input, search_area(input).0)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        puzzle2(input, search_area(input).1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap(), 20), 56000011);
+    }
+
+    #[test]
+    fn test_generate() {
+        common::fuzz::check(0..5, |rng| {
+            let (input, frequency) = plant(rng, 5);
+            assert_eq!(Day15::part2(&parse(&input).unwrap()), frequency);
+        });
+    }
+}
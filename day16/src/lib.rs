@@ -0,0 +1,201 @@
+// ref: https://www.youtube.com/watch?v=DgqkVDr1WX8&ab_channel=chrisbiscardi
+
+use std::collections::HashMap;
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug)]
+struct Valve {
+    name: String,
+    flowrate: usize,
+    tunnels: Vec<String>,
+}
+
+fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let src = Source::new(input);
+    let valve_name = |name: &str| {
+        if name.len() == 2 && name.chars().all(|c| c.is_ascii_uppercase()) {
+            Ok(name.to_string())
+        } else {
+            Err(src.error(name, "a valve name of two uppercase letters"))
+        }
+    };
+    let mut names = vec![];
+    let mut valves = input.lines().map(|l| {
+        let (name, left) = src.split_once(src.prefix(l, "Valve ")?, " has flow rate=")?;
+        let (flowrate, left) = src.split_once(left, "; ")?;
+        let flowrate = src.parse::<usize>(flowrate, "a flow rate")?;
+        let left = src.prefix(left, "tunnels lead to valves ")
+            .or_else(|_| src.prefix(left, "tunnel leads to valve "))
+            .map_err(|_| src.error(left, "a list of tunnels"))?;
+        let tunnels = left.split(", ").map(|t| {
+            names.push(t);
+            valve_name(t)
+        }).collect::<Result<_, _>>()?;
+        Ok(Valve { name: valve_name(name)?, flowrate, tunnels })
+    }).collect::<Result<Vec<_>, _>>()?;
+    for name in names {
+        if !valves.iter().any(|v| v.name == name) {
+            return Err(src.error(name, "an existing valve"));
+        }
+    }
+    // the search starts at index 0 and tracks opened valves in the bits of a usize
+    if !valves.iter().any(|v| v.name == "AA") {
+        return Err(src.missing(input.trim_end(), "a valve named AA"));
+    }
+    if valves.len() > usize::BITS as usize {
+        return Err(src.error(input.lines().nth(usize::BITS as usize).unwrap(), format!("at most {} valves", usize::BITS)));
+    }
+    valves.sort_unstable_by_key(|v| v.name.clone());
+    Ok(valves)
+}
+
+fn build(valves: &[Valve]) -> (Vec<usize>, Vec<Vec<usize>>) {
+    let lookup = valves.iter().enumerate().map(|(i, v)| (v.name.clone(), i)).collect::<HashMap<_, _>>();
+    let mut flowrates = vec![0; valves.len()];
+    let mut tunnels = vec![vec![]; valves.len()];
+    for v in valves {
+        let i = *lookup.get(&v.name).unwrap();
+        flowrates[i] = v.flowrate;
+        for name in &v.tunnels {
+            let j = *lookup.get(name).unwrap();
+            tunnels[i].push(j);
+        }
+    }
+    (flowrates, tunnels)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dfs(cur: usize, opened: usize, minutes: usize, total_minutes: usize, other_players: usize,
+       cache: &mut HashMap<(usize, usize, usize, usize), usize>, flowrates: &[usize], tunnels: &[Vec<usize>]) -> usize {
+    if minutes == 0 {
+        return if other_players > 0 { // play it again
+            dfs(0, opened, total_minutes, total_minutes, other_players - 1, cache, flowrates, tunnels)
+        } else {
+            0
+        };
+    }
+
+    let key = (cur, opened, minutes, other_players);
+    if cache.contains_key(&key) {
+        return *cache.get(&key).unwrap();
+    }
+
+    let mut res = 0;
+    let closed = opened & (1 << cur) == 0;
+    if closed && flowrates[cur] > 0 {
+        let new_opened = opened | (1 << cur); // open this valve
+        res = res.max((minutes - 1) * flowrates[cur] + dfs(cur, new_opened, minutes - 1, total_minutes, other_players, cache, flowrates, tunnels));
+    }
+    for &next in &tunnels[cur] {
+        res = res.max(dfs(next, opened, minutes - 1, total_minutes, other_players, cache, flowrates, tunnels));
+    }
+    cache.insert(key, res);
+    res
+}
+
+pub fn puzzle1(flowrates: &[usize], tunnels: &[Vec<usize>]) -> usize {
+    dfs(0, 0, 30, 30, 0, &mut HashMap::new(), flowrates, tunnels)
+}
+
+pub fn puzzle2(flowrates: &[usize], tunnels: &[Vec<usize>]) -> usize {
+    dfs(0, 0, 26, 26, 1, &mut HashMap::new(), flowrates, tunnels)
+}
+
+/// `size` valves, at most 64, a third of them but no more than the puzzle's 15 with a flow rate,
+/// connected by a random tree of tunnels and a few more.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, usize::BITS as usize);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < size {
+        let name = rng.word("ABCDEFGHIJKLMNOPQRSTUVWXYZ", 2);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut tunnels = vec![vec![]; size];
+    for i in 1..size {
+        let mut connect = |j: usize| if i != j && !tunnels[i].contains(&j) {
+            tunnels[i].push(j);
+            tunnels[j].push(i);
+        };
+        connect(rng.range(0..=i - 1));
+        if rng.chance(0.3) {
+            connect(rng.range(0..=size - 1));
+        }
+    }
+    let mut flowing = 0;
+    (0..size).map(|i| {
+        let rate = if i > 0 && flowing < 15 && rng.chance(1.0 / 3.0) { rng.range(1..=25) } else { 0 };
+        flowing += usize::from(rate > 0);
+        let targets = tunnels[i].iter().map(|&j| names[j].as_str()).collect::<Vec<_>>();
+        let leads = if targets.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        format!("Valve {} has flow rate={}; {} {}\n", names[i], rate, leads, targets.join(", "))
+    }).collect()
+}
+
+const INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = (Vec<usize>, Vec<Vec<usize>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("1651"), Some("1707")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(build(&parse(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(&input.0, &input.1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        let (flowrates, tunnels) = build(&parse(INPUT).unwrap());
+        assert_eq!(puzzle1(&flowrates, &tunnels), 1651);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        let (flowrates, tunnels) = build(&parse(INPUT).unwrap());
+        assert_eq!(puzzle2(&flowrates, &tunnels), 1707);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(&INPUT.replace("valves CC, AA\n", "valves CC, KK\n")).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 55: expected an existing valve, found \"KK\"");
+        let e = parse(&INPUT.replace("tunnel leads to valve GG", "tunnels lead to valve GG")).unwrap_err();
+        assert_eq!((e.line, e.column), (8, 28));
+        assert!(parse(&INPUT.replace("AA", "KK")).is_err());
+    }
+}
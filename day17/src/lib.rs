@@ -0,0 +1,192 @@
+// ref: https://github.com/AxlLind/AdventOfCode2022/blob/main/src/bin/17.rs
+
+use std::collections::HashMap;
+
+use common::image::{self, BLACK, GOLD, WHITE};
+use common::{Example, ParseError, Rng, Solution, Source};
+use grid::Grid;
+
+const ROCKS_LIST: [&[(usize, usize)]; 5] = [
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+    &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(0, 0), (0, 1), (1, 0), (1, 1)],
+];
+
+// the chamber is stored bottom up: row 0 is the floor level
+fn get_height(map: &Grid<bool>) -> usize {
+    map.rows().position(|row| row.iter().all(|&rock| !rock)).unwrap()
+}
+
+// the height of the chamber from the heights of its columns, kept as rocks come to rest so that
+// the chamber isn't scanned for every rock
+fn height(tops: &[usize; 7]) -> usize {
+    tops.iter().copied().max().unwrap()
+}
+
+fn can_fit(map: &Grid<bool>, rocks: &[(usize, usize)], h: usize, w: usize) -> bool {
+    rocks.iter().all(|(dh, dw)| map.get((h + dh, w + dw)).is_some_and(|&rock| !rock))
+}
+
+// rocks don't fall this far below the top, so deeper holes all look the same; without a limit a column
+// the jets keep rocks away from would get deeper forever and the pattern never repeat
+const SKYLINE_DEPTH: usize = 100;
+
+// used as key: how far below the top each column's highest rock is
+fn skyline(tops: &[usize; 7]) -> [usize; 7] {
+    let h = height(tops);
+    std::array::from_fn(|i| if tops[i] == 0 { usize::MAX } else { (h - tops[i]).min(SKYLINE_DEPTH) })
+}
+
+// a recording shows this many rows from the top of the chamber down, every few rocks
+const FRAME_ROWS: usize = 48;
+const FRAME_EVERY: usize = 5;
+
+// records the top of the chamber, the rock that just came to rest at (h, w) standing out
+fn frame(name: &str, map: &Grid<bool>, rocks: &[(usize, usize)], (h, w): (usize, usize)) {
+    if !image::recording() {
+        return;
+    }
+    let top = get_height(map) + 3; // as high as a rock starts falling from
+    image::frame(name, 7, FRAME_ROWS, |(row, col)| match (top - 1).checked_sub(row) {
+        None => WHITE, // the floor
+        Some(row) if rocks.iter().any(|&(dh, dw)| (h + dh, w + dw) == (row, col)) => GOLD,
+        Some(row) if map[(row, col)] => WHITE,
+        Some(_) => BLACK,
+    });
+}
+
+fn simulate(name: &str, jets: &[u8], rocks_limit: usize) -> usize {
+    let mut map = Grid::new(7, 100000, false);
+    let mut tops = [0; 7]; // the height of each column
+    let mut i = 0; // rocks index
+    let mut j = 0; // move index, jets of hot gas
+    let mut cache = HashMap::new(); // key -> (i, height)
+    let mut repeated_height = 0;
+    while i < rocks_limit {
+        let rocks = ROCKS_LIST[i % ROCKS_LIST.len()];
+        let (mut h, mut w) = (height(&tops) + 3, 2); // init pos
+        loop {
+            match jets[j % jets.len()] {
+                b'>' => if can_fit(&map, rocks, h, w + 1) { w += 1 },
+                b'<' => if w > 0 && can_fit(&map, rocks, h, w - 1) { w -= 1 },
+                _ => unreachable!(),
+            }
+            j += 1;
+            if h == 0 || !can_fit(&map, rocks, h - 1, w) {
+                break;
+            }
+            h -= 1;
+        }
+        for (dh, dw) in rocks.iter() {
+            map[(h + dh, w + dw)] = true;
+            tops[w + dw] = tops[w + dw].max(h + dh + 1);
+        }
+        if i.is_multiple_of(FRAME_EVERY) {
+            frame(name, &map, rocks, (h, w));
+        }
+        let key = (i % ROCKS_LIST.len(), j % jets.len(), skyline(&tops));
+        if let Some((prev_index, prev_height)) = cache.get(&key) {
+            let mut repeats = (rocks_limit - prev_index) / (i - prev_index) - 1;
+            if i + (i - prev_index) * repeats >= rocks_limit {
+                repeats -= 1; // take care of boundary
+            }
+            if repeats > 0 {
+                common::debug!("rocks {} to {} repeat, skipping {} cycles of them", prev_index, i, repeats);
+            }
+            i += (i - prev_index) * repeats;
+            repeated_height += (height(&tops) - prev_height) * repeats;
+        } else {
+            cache.insert(key, (i, height(&tops)));
+        }
+        i += 1;
+        common::trace!("after rock {}:\n{}", i, display(&map));
+    }
+    repeated_height + height(&tops)
+}
+
+fn display(map: &Grid<bool>) -> String {
+    let mut lines = map.rows().take(get_height(map))
+        .map(|row| format!("|{}|", row.iter().map(|&rock| if rock { '#' } else { '.' }).collect::<String>()))
+        .collect::<Vec<_>>();
+    lines.insert(0, "+-------+".to_string());
+    lines.reverse();
+    lines.join("\n")
+}
+
+pub fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+    let src = Source::new(input);
+    let jets = input.trim_end();
+    src.check_chars(jets, |c| c == '<' || c == '>', "< or >")?;
+    if jets.is_empty() {
+        return Err(src.missing(jets, "a jet pattern"));
+    }
+    Ok(jets.as_bytes().to_vec())
+}
+
+pub fn puzzle1(jets: &[u8]) -> usize {
+    simulate("day17-part1", jets, 2022)
+}
+
+pub fn puzzle2(jets: &[u8]) -> usize {
+    simulate("day17-part2", jets, 1_000_000_000_000)
+}
+
+/// A jet pattern of `size` pushes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    rng.word("<>", size.max(1)) + "\n"
+}
+
+const INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("3068"), Some("1514285714288")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 3068);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 1514285714288);
+    }
+
+    #[test]
+    fn test_skyline() {
+        // holes deeper than SKYLINE_DEPTH all look the same, and empty columns differ from them
+        assert_eq!(skyline(&[1, 150, 149, 150, 150, 150, 0]), [SKYLINE_DEPTH, 0, 1, 0, 0, 0, usize::MAX]);
+        assert_eq!(skyline(&[1, 150, 0, 0, 0, 0, 0]), skyline(&[40, 150, 0, 0, 0, 0, 0]));
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use common::{search, Example, ParseError, Rng, Solution, Source};
+
+pub fn puzzle1(drops: &HashSet<(i8, i8, i8)>) -> usize {
+    drops.iter().flat_map(|&d| sides(d)).filter(|s| !drops.contains(s)).count()
+}
+
+pub fn puzzle2(drops: &HashSet<(i8, i8, i8)>) -> usize {
+    let min = drops.iter().flat_map(|(x, y, z)| [x, y, z]).min().unwrap() - 1;
+    let max = drops.iter().flat_map(|(x, y, z)| [x, y, z]).max().unwrap() + 1;
+    // flood the air around the droplet from a corner of the box just enclosing it
+    let air = search::reachable((min, min, min), usize::MAX, |&p| {
+        sides(p).into_iter().filter(|s| !drops.contains(s) && [s.0, s.1, s.2].iter().all(|&i| min <= i && i <= max))
+    });
+    drops.iter().flat_map(|&d| sides(d)).filter(|s| air.contains_key(s)).count()
+}
+
+pub fn parse(input: &str) -> Result<HashSet<(i8, i8, i8)>, ParseError> {
+    let src = Source::new(input);
+    // neighbours and the flood fill bounds step one past each coordinate, so leave room in an i8
+    let coordinate = |s: &str| match s.parse::<i8>() {
+        Ok(n) if (-126..=126).contains(&n) => Ok(n),
+        _ => Err(src.error(s, "a coordinate from -126 to 126")),
+    };
+    let drops = input.lines().map(|l| {
+        let mut split = l.split(",");
+        let x = coordinate(src.next(&mut split, l, "x")?)?;
+        let y = coordinate(src.next(&mut split, l, "y")?)?;
+        let z = coordinate(src.next(&mut split, l, "z")?)?;
+        Ok((x, y, z))
+    }).collect::<Result<HashSet<_>, _>>()?;
+    if drops.is_empty() {
+        return Err(src.missing(input, "a cube"));
+    }
+    Ok(drops)
+}
+
+fn sides((x, y, z): (i8, i8, i8)) -> [(i8, i8, i8); 6] {
+    [(x - 1, y, z), (x + 1, y, z), (x, y - 1, z), (x, y + 1, z), (x, y, z - 1), (x, y, z + 1)]
+}
+
+/// `size` distinct cubes, packed into a box so that about half of it is lava.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size.max(1) * 2) as f64).cbrt().ceil().min(126.0) as i8;
+    let size = size.clamp(1, side as usize * side as usize * side as usize);
+    let mut cubes = HashSet::new();
+    let mut input = String::new();
+    while cubes.len() < size {
+        let cube = [(); 3].map(|_| rng.range(0..=side - 1));
+        if cubes.insert(cube) {
+            input += &format!("{},{},{}\n", cube[0], cube[1], cube[2]);
+        }
+    }
+    input
+}
+
+const INPUT: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = HashSet<(i8, i8, i8)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("64"), Some("58")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 64);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 58);
+    }
+}
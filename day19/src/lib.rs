@@ -0,0 +1,255 @@
+use common::{search, Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Resources {
+    ore: u16,
+    clay: u16,
+    obsidian: u16,
+    geode: u16,
+    ore_bots: u16,
+    clay_bots: u16,
+    obsidian_bots: u16,
+    geode_bots: u16,
+}
+
+impl Resources {
+    fn collect(&mut self) {
+        self.ore += self.ore_bots;
+        self.clay += self.clay_bots;
+        self.obsidian += self.obsidian_bots;
+        self.geode += self.geode_bots;
+    }
+
+    fn try_build_geode(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.geode.0 && self.obsidian >= blueprint.geode.1 {
+            let mut res = *self;
+            res.ore -= blueprint.geode.0;
+            res.obsidian -= blueprint.geode.1;
+            res.collect();
+            res.geode_bots += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn try_build_obsidian(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.obsidian.0 && self.clay >= blueprint.obsidian.1
+            && self.obsidian_bots < blueprint.geode.1 {
+            let mut res = *self;
+            res.ore -= blueprint.obsidian.0;
+            res.clay -= blueprint.obsidian.1;
+            res.collect();
+            res.obsidian_bots += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn try_build_clay(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.clay && self.clay_bots < blueprint.obsidian.1 {
+            let mut res = *self;
+            res.ore -= blueprint.clay;
+            res.collect();
+            res.clay_bots += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    fn try_build_ore(&self, blueprint: &Blueprint) -> Option<Self> {
+        let max_ore_costs = *[blueprint.ore, blueprint.clay, blueprint.obsidian.0, blueprint.geode.0].iter().max().unwrap();
+        if self.ore >= blueprint.ore && self.ore_bots < max_ore_costs {
+            let mut res = *self;
+            res.ore -= blueprint.ore;
+            res.collect();
+            res.ore_bots += 1;
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Resources {
+    fn default() -> Self {
+        Resources {
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_bots: 1,
+            clay_bots: 0,
+            obsidian_bots: 0,
+            geode_bots: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u16,
+    ore: u16,
+    clay: u16,
+    obsidian: (u16, u16),
+    geode: (u16, u16),
+}
+
+// `#` stands for a number; blueprints may be wrapped over several lines, so only the words matter
+const BLUEPRINT: &str = "Blueprint #: Each ore robot costs # ore. Each clay robot costs # ore. \
+    Each obsidian robot costs # ore and # clay. Each geode robot costs # ore and # obsidian.";
+
+pub fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let src = Source::new(input);
+    let mut tokens = input.split_whitespace().peekable();
+    let mut blueprints = vec![];
+    while tokens.peek().is_some() {
+        let mut digits = vec![];
+        for pattern in BLUEPRINT.split_whitespace() {
+            let token = tokens.next().ok_or_else(|| src.missing(input.trim_end(), format!("{:?}", pattern)))?;
+            if let Some(suffix) = pattern.strip_prefix('#') {
+                let n = token.strip_suffix(suffix)
+                    .ok_or_else(|| src.error(token, format!("a number followed by {:?}", suffix)))?;
+                digits.push(src.parse::<u16>(n, "a number")?);
+            } else if token != pattern {
+                return Err(src.error(token, format!("{:?}", pattern)));
+            }
+        }
+        blueprints.push(Blueprint {
+            id: digits[0],
+            ore: digits[1],
+            clay: digits[2],
+            obsidian: (digits[3], digits[4]),
+            geode: (digits[5], digits[6]),
+        });
+    }
+    Ok(blueprints)
+}
+
+/// The resources a minute later: a geode robot if it can be built, else any other robot or none.
+fn successors(resources: &Resources, blueprint: &Blueprint) -> impl Iterator<Item = Resources> {
+    // a fixed array rather than a vector, as this runs for every one of millions of states
+    let next = match resources.try_build_geode(blueprint) {
+        Some(resources) => [Some(resources), None, None, None],
+        None => {
+            let mut idle = *resources;
+            idle.collect();
+            [
+                resources.try_build_obsidian(blueprint),
+                resources.try_build_clay(blueprint),
+                resources.try_build_ore(blueprint),
+                Some(idle),
+            ]
+        }
+    };
+    next.into_iter().flatten()
+}
+
+/// The most geodes that can be opened in `time` minutes; geodes are never lost, so any state reached
+/// in time counts. What is built in the last minute opens no geode, so the search stops a minute early,
+/// sparing it the largest layer of states, and lets the geode robots work that last minute.
+fn max_geodes(time: u16, blueprint: &Blueprint) -> u16 {
+    let steps = usize::from(time.saturating_sub(1));
+    let reached = search::reachable(Resources::default(), steps, |r| successors(r, blueprint));
+    let last_minute = |resources: &Resources| if time > 0 { resources.geode_bots } else { 0 };
+    let best = reached.keys().max_by_key(|resources| resources.geode + last_minute(resources)).unwrap();
+    let geodes = best.geode + last_minute(best);
+    common::debug!("blueprint {}: {} geodes in {} minutes, {} states reached",
+                   blueprint.id, geodes, time, reached.len());
+    common::trace!("blueprint {}: best state a minute before the end {:?}", blueprint.id, best);
+    geodes
+}
+
+pub fn puzzle1(blueprints: &[Blueprint]) -> u16 {
+    blueprints.iter().map(|blueprint| {
+        blueprint.id * max_geodes(24, blueprint)
+    }).sum()
+}
+
+pub fn puzzle2(blueprints: &[Blueprint]) -> u16 {
+    blueprints.iter().take(3).map(|blueprint| {
+        max_geodes(32, blueprint)
+    }).product()
+}
+
+/// `size` blueprints with costs in the ranges of the real ones.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1)).map(|id| {
+        let costs = [id, rng.range(2..=4), rng.range(2..=4), rng.range(2..=4), rng.range(5..=20),
+                     rng.range(2..=4), rng.range(5..=20)];
+        let mut parts = BLUEPRINT.split('#');
+        let mut blueprint = parts.next().unwrap().to_string();
+        for (cost, part) in costs.iter().zip(parts) {
+            blueprint += &format!("{}{}", cost, part);
+        }
+        blueprint.replace("    ", "") + "\n"
+    }).collect()
+}
+
+const INPUT: &str = include_str!("../input_test.txt");
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<Blueprint>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("33"), Some("3472")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> u16 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u16 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 33);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 3472);
+    }
+
+    #[test]
+    fn test_last_minute() {
+        // stopping a minute early finds as many geodes as searching every minute
+        let blueprints = parse(INPUT).unwrap();
+        for time in [0, 1, 12, 20] {
+            let reached = search::reachable(Resources::default(), usize::from(time), |r| successors(r, &blueprints[0]));
+            assert_eq!(max_geodes(time, &blueprints[0]), reached.keys().map(|r| r.geode).max().unwrap(), "{}", time);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse(&INPUT.replace("3 ore and 8 clay", "3 ore and eight clay")).unwrap_err();
+        assert_eq!(e.to_string(), "line 10, column 39: expected a number, found \"eight\"");
+        let e = parse(&INPUT.replace("Each clay", "Each cloy")).unwrap_err();
+        assert_eq!((e.line, e.column, e.expected.as_str()), (3, 8, "\"clay\""));
+        let e = parse(&INPUT.replace(" and 12 obsidian.", "")).unwrap_err();
+        assert_eq!((e.line, e.column, e.found.as_str()), (11, 31, ""));
+    }
+}
@@ -0,0 +1,119 @@
+extern crate core;
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug, Clone, Copy)]
+struct Num {
+    id: i64,
+    val: i64,
+}
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let src = Source::new(input);
+    let values = input.lines().map(|l| src.parse::<i64>(l, "a number")).collect::<Result<Vec<_>, _>>()?;
+    // the grove coordinates are counted from the 0
+    if !values.contains(&0) {
+        return Err(src.missing(input.trim_end(), "a 0 in the file"));
+    }
+    Ok(values)
+}
+
+fn decrypt(values: &[i64], key: i64) -> Vec<Num> {
+    let mut id = 0;
+    values.iter().map(|&val| {
+        id += 1;
+        Num { id, val: val * key }
+    }).collect()
+}
+
+fn mix(nums: Vec<Num>, iterations: i64) -> i64 {
+    let mut ans = nums.clone();
+    for _ in 0..iterations {
+        for num in &nums {
+            let pos = ans.iter().position(|x| x.id == num.id).unwrap();
+            let num = ans.remove(pos);
+            // a lone number has nowhere to move
+            let pos = (pos as i64 + num.val).rem_euclid(ans.len().max(1) as i64) as usize;
+            ans.insert(pos, num);
+        }
+    }
+    let pos = ans.iter().position(|&x| x.val == 0).unwrap();
+    [1000, 2000, 3000].iter().map(|&nth| ans[(pos + nth) % ans.len()].val).sum()
+}
+
+pub fn puzzle1(values: &[i64]) -> i64 {
+    mix(decrypt(values, 1), 1)
+}
+
+pub fn puzzle2(values: &[i64]) -> i64 {
+    mix(decrypt(values, 811589153), 10)
+}
+
+/// An encrypted file of `size` numbers, exactly one of them 0.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (1..size.max(1)).map(|_| {
+        let n = rng.range(1..=10000);
+        if rng.chance(0.5) { -n } else { n }
+    }).collect::<Vec<_>>();
+    numbers.insert(rng.range(0..=numbers.len()), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+const INPUT: &str = "1
+2
+-3
+3
+-2
+0
+4";
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("3"), Some("1623178306")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 1623178306);
+    }
+
+    #[test]
+    fn test_lone_number() {
+        // with the others removed, a lone number moves in a list of none and stays put
+        assert_eq!(puzzle1(&[0]), 0);
+        assert_eq!(puzzle2(&[0]), 0);
+    }
+}
@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use common::{Example, ParseError, Rng, Solution, Source};
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Num(i64),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+pub fn parse(input: &str) -> Result<HashMap<String, Operation>, ParseError> {
+    let src = Source::new(input);
+    let mut references = vec![];
+    let monkeys = input.lines().map(|l| {
+        let (name, monkey) = src.split_once(l, ": ")?;
+        if let Ok(num) = monkey.parse::<i64>() {
+            Ok((name.to_string(), Operation::Num(num)))
+        } else {
+            let mut split = monkey.split(' ');
+            let a = src.next(&mut split, monkey, "a number or a monkey name")?;
+            let o = src.next(&mut split, monkey, "an operator")?;
+            let b = src.next(&mut split, monkey, "a monkey name")?;
+            references.extend([a, b]);
+            let (a, b) = (a.to_string(), b.to_string());
+            Ok((name.to_string(), match o {
+                "+" => Operation::Add(a, b),
+                "-" => Operation::Sub(a, b),
+                "*" => Operation::Mul(a, b),
+                "/" => Operation::Div(a, b),
+                _ => return Err(src.error(o, "+, -, * or /")),
+            }))
+        }
+    }).collect::<Result<HashMap<_, _>, _>>()?;
+    for name in references {
+        if !monkeys.contains_key(name) {
+            return Err(src.error(name, "an existing monkey name"));
+        }
+    }
+    for name in ["root", "humn"] {
+        if !monkeys.contains_key(name) {
+            return Err(src.missing(input.trim_end(), format!("a monkey named {}", name)));
+        }
+    }
+    Ok(monkeys)
+}
+
+fn dfs(monkeys: &HashMap<String, Operation>, name: &str) -> i64 {
+    match monkeys.get(name).unwrap() {
+        Operation::Num(num) => *num,
+        Operation::Add(a, b) => dfs(monkeys, a) + dfs(monkeys, b),
+        Operation::Sub(a, b) => dfs(monkeys, a) - dfs(monkeys, b),
+        Operation::Mul(a, b) => dfs(monkeys, a) * dfs(monkeys, b),
+        Operation::Div(a, b) => dfs(monkeys, a) / dfs(monkeys, b),
+    }
+}
+
+pub fn puzzle1(monkeys: &HashMap<String, Operation>) -> i64 {
+    dfs(monkeys, "root")
+}
+
+pub fn puzzle2(monkeys: &HashMap<String, Operation>) -> i64 {
+    let mut monkeys = monkeys.clone();
+    monkeys.insert("root".to_string(), match monkeys.get("root").unwrap() {
+        Operation::Add(a, b) => Operation::Sub(a.clone(), b.clone()),
+        Operation::Sub(a, b) => Operation::Sub(a.clone(), b.clone()),
+        Operation::Mul(a, b) => Operation::Sub(a.clone(), b.clone()),
+        Operation::Div(a, b) => Operation::Sub(a.clone(), b.clone()),
+        _ => unreachable!(),
+    });
+
+    if let Some(humn) = search(&mut monkeys, |v| v.cmp(&0)) {
+        return humn;
+    }
+    if let Some(humn) = search(&mut monkeys, |v| 0.cmp(&v)) {
+        return humn;
+    }
+    unreachable!()
+}
+
+fn search(monkeys: &mut HashMap<String, Operation>, cmp: fn(i64) -> Ordering) -> Option<i64> {
+    let mut l = 0;
+    let mut r = i64::MAX / 10000;
+    while l < r {
+        let m = (l + r) / 2;
+        monkeys.insert("humn".to_string(), Operation::Num(m)).unwrap();
+        let v = dfs(monkeys, "root");
+        match cmp(v) {
+            Ordering::Equal => return Some(m),
+            Ordering::Less => { l = m + 1; }
+            Ordering::Greater => { r = m; }
+        }
+    }
+    None
+}
+
+/// About `size` monkeys. `humn` is a leaf on one side of `root` only, every operation on its way up has
+/// a positive number as the other operand, and it divides rather than is divided, so the value at
+/// `root` grows or shrinks steadily with `humn` and the binary search in part 2 has an answer.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names = HashSet::from(["root".to_string(), "humn".to_string()]);
+    let mut lines = vec![format!("humn: {}", rng.range(1..=5000))];
+    let mut name = |rng: &mut Rng| loop {
+        let name = rng.word("abcdefghijklmnopqrstuvwxyz", 4);
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    // walk up from the answer to part 2, keeping the value the yell on each monkey on the way has for it
+    let (mut node, mut value) = ("humn".to_string(), rng.range(1..=1_000_000_000_000i64));
+    // how much the path multiplies humn by at most, so that the search's guesses do not overflow
+    let mut factor = 1;
+    for _ in 0..(size / 4).max(1) {
+        let n = rng.range(1..=20);
+        let budget = rng.range(0..=2);
+        let operand = constant(rng, &mut name, &mut lines, n, budget);
+        let (op, next) = match rng.range(0..=4) {
+            1 if value > n => (format!("{} - {}", node, operand), value - n),
+            2 => (format!("{} - {}", operand, node), n - value),
+            3 if n > 1 && factor * n <= 1000 => {
+                factor *= n;
+                (format!("{} * {}", node, operand), value * n)
+            }
+            4 if n > 1 => {
+                factor = (factor + n - 1) / n;
+                (format!("{} / {}", node, operand), value / n)
+            }
+            _ => (format!("{} + {}", node, operand), value + n),
+        };
+        node = name(rng);
+        lines.push(format!("{}: {}", node, op));
+        value = next;
+    }
+    let other = constant(rng, &mut name, &mut lines, value, size / 2);
+    lines.push(format!("root: {} {} {}", node, rng.choose(&["+", "-"]), other));
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Adds monkeys yelling `value` between them, up to `budget` operations deep, and returns the top one.
+fn constant(rng: &mut Rng, name: &mut impl FnMut(&mut Rng) -> String, lines: &mut Vec<String>,
+            value: i64, budget: usize) -> String {
+    let monkey = name(rng);
+    if budget == 0 || value < 2 {
+        lines.push(format!("{}: {}", monkey, value));
+        return monkey;
+    }
+    let k = rng.range(2..=5);
+    let (a, op, b) = match rng.range(0..=3) {
+        0 => {
+            let b = rng.range(1..=20);
+            (value + b, '-', b)
+        }
+        1 if value % k == 0 => (value / k, '*', k),
+        2 if value < 1_000_000_000_000 => (value * k, '/', k),
+        _ => {
+            let b = rng.range(1..=value - 1);
+            (value - b, '+', b)
+        }
+    };
+    let split = rng.range(0..=budget - 1);
+    let a = constant(rng, name, lines, a, split);
+    let b = constant(rng, name, lines, b, budget - 1 - split);
+    lines.push(format!("{}: {} {} {}", monkey, a, op, b));
+    monkey
+}
+
+const INPUT: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = HashMap<String, Operation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("152"), Some("301")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 152);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 301);
+    }
+}
@@ -0,0 +1,180 @@
+use common::image::{self, BLACK, GREEN};
+use common::{Example, ParseError, Rng, Solution, Source};
+use grid::Grid;
+
+const ADJACENT: [(i32, i32); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+const MOVES: [[(i32, i32); 3]; 4] = [
+    [(-1, -1), (0, -1), (1, -1)],
+    [(-1, 1), (0, 1), (1, 1)],
+    [(-1, -1), (-1, 0), (-1, 1)],
+    [(1, -1), (1, 0), (1, 1)]
+];
+
+pub fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let grid = Grid::parse(input, "'.' or '#'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if grid.find(&true).is_none() {
+        return Err(Source::new(input).missing(input.trim_end(), "at least one elf"));
+    }
+    Ok(grid.find_all(&true).map(|(y, x)| (x as i32, y as i32)).collect())
+}
+
+/// The elves on the smallest grid around them, so that checking a position is a lookup rather than a scan.
+struct Elves {
+    grid: Grid<bool>,
+    min_x: i32,
+    min_y: i32,
+}
+
+impl Elves {
+    fn new(positions: &[(i32, i32)]) -> Self {
+        let min_x = positions.iter().map(|&(x, _)| x).min().unwrap();
+        let max_x = positions.iter().map(|&(x, _)| x).max().unwrap();
+        let min_y = positions.iter().map(|&(_, y)| y).min().unwrap();
+        let max_y = positions.iter().map(|&(_, y)| y).max().unwrap();
+        let mut grid = Grid::new((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize, false);
+        for &(x, y) in positions {
+            grid[((y - min_y) as usize, (x - min_x) as usize)] = true;
+        }
+        Elves { grid, min_x, min_y }
+    }
+
+    fn contains(&self, x: i32, y: i32) -> bool {
+        let pos = ((y - self.min_y) as usize, (x - self.min_x) as usize);
+        x >= self.min_x && y >= self.min_y && self.grid.get(pos) == Some(&true)
+    }
+}
+
+fn is_candidate(elves: &Elves, x: i32, y: i32) -> bool {
+    ADJACENT.iter().any(|(dx, dy)| elves.contains(x + dx, y + dy))
+}
+
+fn simulate(name: &str, mut positions: Vec<(i32, i32)>, round: usize) -> (Vec<(i32, i32)>, usize) {
+    for r in 0..round {
+        let elves = Elves::new(&positions);
+        let candidates = positions.iter().enumerate()
+            .filter(|(_, &(x, y))| is_candidate(&elves, x, y))
+            .map(|(i, _)| i).collect::<Vec<_>>();
+        let first_proposes = candidates.iter().map(|&i| {
+            let (x, y) = positions[i];
+            for i in r..r + MOVES.len() {
+                if MOVES[i % MOVES.len()].iter().all(|&(dx, dy)| !elves.contains(x + dx, y + dy)) {
+                    return Some(match i % MOVES.len() {
+                        0 => (x, y - 1), // north
+                        1 => (x, y + 1), // south
+                        2 => (x - 1, y), // west
+                        3 => (x + 1, y), // east
+                        _ => unreachable!(),
+                    });
+                }
+            }
+            None
+        }).collect::<Vec<_>>();
+        let second_proposes = first_proposes.iter().enumerate().map(|(i, &p)| {
+            if first_proposes.iter().enumerate().any(|(j, &other)| i != j && p == other) {
+                None
+            } else {
+                p
+            }
+        }).collect::<Vec<_>>();
+        if second_proposes.iter().all(|p| p.is_none()) {
+            common::debug!("no elf moves in round {}", r + 1);
+            return (positions, r + 1);
+        }
+        common::debug!("round {}: {} of {} elves move", r + 1, second_proposes.iter().flatten().count(), positions.len());
+        candidates.into_iter().zip(second_proposes).for_each(|(i, p)| {
+            if let Some(pos) = p {
+                positions[i] = pos;
+            }
+        });
+        common::trace!("== End of Round {} ==\n{}", r + 1,
+                       Elves::new(&positions).grid.render(|&elf| if elf { '#' } else { '.' }));
+        if image::recording() {
+            Elves::new(&positions).grid.frame(name, |&elf| if elf { GREEN } else { BLACK });
+        }
+    }
+    (positions, round)
+}
+
+pub fn puzzle1(positions: &[(i32, i32)]) -> usize {
+    let (positions, _) = simulate("day23-part1", positions.to_vec(), 10);
+    Elves::new(&positions).grid.find_all(&false).count()
+}
+
+pub fn puzzle2(positions: &[(i32, i32)]) -> usize {
+    let (_, round) = simulate("day23-part2", positions.to_vec(), usize::MAX);
+    round
+}
+
+/// A square scan `size` tiles wide with an elf on about half of them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut elves = Grid::new(size, size, '.');
+    for pos in elves.positions().collect::<Vec<_>>() {
+        if rng.chance(0.5) {
+            elves[pos] = '#';
+        }
+    }
+    elves[(rng.range(0..=size - 1), rng.range(0..=size - 1))] = '#';
+    elves.to_string() + "\n"
+}
+
+const INPUT: &str = "..............
+..............
+.......#......
+.....###.#....
+...#...#.#....
+....#...##....
+...#.###......
+...##.#.##....
+....#..#......
+..............
+..............
+..............";
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Vec<(i32, i32)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("110"), Some("20")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), 110);
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 20);
+    }
+}
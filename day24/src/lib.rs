@@ -0,0 +1,216 @@
+use std::fmt;
+
+use common::image::{self, BLACK, BLUE, GOLD, WHITE};
+use common::{search, Example, ParseError, Rng, Solution, Source};
+use grid::{Grid, Pos};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valley {
+    /// A bit per direction of `DIR` for every blizzard on a tile.
+    blizzards: Grid<u8>,
+}
+
+const DIR: [(isize, isize); 5] = [(-1, 0), (1, 0), (0, -1), (0, 1), (0, 0)]; // up, down, left, right, wait
+
+pub fn parse(input: &str) -> Result<Valley, ParseError> {
+    let src = Source::new(input);
+    let blizzards = Grid::parse(input, "'#', '.' or a blizzard", |c| match c {
+        '#' | '.' => Some(0),
+        _ => "^v<>".find(c).map(|d| 1 << d),
+    })?;
+    if blizzards.height() < 3 || blizzards.width() < 3 {
+        return Err(src.missing(input.trim_end(), "a valley of at least 3 by 3 tiles"));
+    }
+    Ok(Valley { blizzards })
+}
+
+impl Valley {
+    fn src(&self) -> Pos {
+        (0, 1)
+    }
+
+    fn dst(&self) -> Pos {
+        (self.blizzards.height() - 1, self.blizzards.width() - 2)
+    }
+
+    fn is_wall(&self, (r, c): Pos) -> bool {
+        let (rows, cols) = (self.blizzards.height(), self.blizzards.width());
+        (r == 0 || r == rows - 1 || c == 0 || c == cols - 1) && (r, c) != self.src() && (r, c) != self.dst()
+    }
+}
+
+fn simulate(blizzards: &Grid<u8>) -> Grid<u8> {
+    let (rows, cols) = (blizzards.height(), blizzards.width());
+    let mut next = Grid::new(cols, rows, 0);
+    for ((r, c), &tile) in blizzards.iter() {
+        for (d, (dr, dc)) in DIR[..4].iter().enumerate().filter(|&(d, _)| tile & 1 << d != 0) {
+            let mut nr = r.wrapping_add_signed(*dr);
+            let mut nc = c.wrapping_add_signed(*dc);
+            if nr == rows - 1 {
+                nr = 1;
+            } else if nr == 0 {
+                nr = rows - 2;
+            }
+            if nc == cols - 1 {
+                nc = 1;
+            } else if nc == 0 {
+                nc = cols - 2;
+            }
+            next[(nr, nc)] |= 1 << d;
+        }
+    }
+    next
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// The tiles covered by a blizzard at every minute, until they are all back where they started.
+fn forecast(valley: &Valley) -> Vec<Grid<bool>> {
+    let (rows, cols) = (valley.blizzards.height() - 2, valley.blizzards.width() - 2);
+    let period = rows / gcd(rows, cols) * cols;
+    std::iter::successors(Some(valley.blizzards.clone()), |blizzards| Some(simulate(blizzards)))
+        .take(period)
+        .map(|blizzards| blizzards.map(|&tile| tile != 0))
+        .collect()
+}
+
+/// The minutes it takes to get from `src` to `dst`, setting off at `minute`, if the blizzards ever let us through.
+/// A recording of the simulation `name` gets a frame of every minute of the way.
+fn cross(name: &str, valley: &Valley, forecast: &[Grid<bool>], src: Pos, dst: Pos, minute: usize) -> Option<usize> {
+    // the time only matters for where the blizzards are, so it wraps around with them
+    let successors = |&(cur, minute): &(Pos, usize)| {
+        let minute = (minute + 1) % forecast.len();
+        DIR.iter().filter_map(move |&d| {
+            let next = valley.blizzards.step(cur, d)?;
+            (!valley.is_wall(next) && !forecast[minute][next]).then_some((next, minute))
+        })
+    };
+    let (steps, path) = search::bfs((src, minute % forecast.len()), successors, |&(cur, _)| cur == dst)?;
+    if image::recording() {
+        let (width, height) = (valley.blizzards.width(), valley.blizzards.height());
+        for (cur, minute) in path {
+            image::frame(name, width, height, |pos| match pos {
+                _ if pos == cur => GOLD,
+                _ if valley.is_wall(pos) => WHITE,
+                _ if forecast[minute][pos] => BLUE,
+                _ => BLACK,
+            });
+        }
+    }
+    Some(steps)
+}
+
+/// The minutes a trip takes, if the blizzards let us make it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Minutes(pub Option<usize>);
+
+impl fmt::Display for Minutes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(minutes) => write!(f, "{}", minutes),
+            None => write!(f, "none, the blizzards never let us through"),
+        }
+    }
+}
+
+pub fn puzzle1(valley: &Valley) -> Option<usize> {
+    cross("day24-part1", valley, &forecast(valley), valley.src(), valley.dst(), 0)
+}
+
+pub fn puzzle2(valley: &Valley) -> Option<usize> {
+    let forecast = forecast(valley);
+    // to the goal, back to the start and to the goal again
+    let cross = |src, dst, minute| cross("day24-part2", valley, &forecast, src, dst, minute);
+    let there = cross(valley.src(), valley.dst(), 0)?;
+    let back = cross(valley.dst(), valley.src(), there)?;
+    Some(there + back + cross(valley.src(), valley.dst(), there + back)?)
+}
+
+/// A valley `size` tiles wide and a fifth as high inside its walls, with a blizzard on about a quarter
+/// of the tiles, none blowing up or down in the columns of the entrance and the exit. Valleys the
+/// blizzards keep us from crossing there, back and there again are thrown away.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(1), (size / 5).max(1));
+    loop {
+        let mut valley = Grid::new(width + 2, height + 2, '#');
+        valley[(0, 1)] = '.';
+        valley[(height + 1, width)] = '.';
+        for (r, c) in (1..=height).flat_map(|r| (1..=width).map(move |c| (r, c))) {
+            let blizzards: &[char] = if c == 1 || c == width { &['<', '>'] } else { &['^', 'v', '<', '>'] };
+            valley[(r, c)] = if rng.chance(0.25) { *rng.choose(blizzards) } else { '.' };
+        }
+        let input = valley.to_string() + "\n";
+        let valley = parse(&input).unwrap();
+        let forecast = forecast(&valley);
+        let cross = |src, dst, minute| cross("day24-generate", &valley, &forecast, src, dst, minute);
+        let crossable = cross(valley.src(), valley.dst(), 0)
+            .and_then(|there| Some(there + cross(valley.dst(), valley.src(), there)?))
+            .and_then(|back| cross(valley.src(), valley.dst(), back))
+            .is_some();
+        if crossable {
+            return input;
+        }
+    }
+}
+
+const INPUT: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Valley;
+    type Answer1 = Minutes;
+    type Answer2 = Minutes;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("18"), Some("54")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Minutes {
+        Minutes(puzzle1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Minutes {
+        Minutes(puzzle2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), Some(18));
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), Some(54));
+    }
+
+    #[test]
+    fn test_no_way_through() {
+        // the blizzard blowing round the only tile is always on it
+        let valley = parse("#.#\n#>#\n#.#").unwrap();
+        assert_eq!((puzzle1(&valley), puzzle2(&valley)), (None, None));
+        assert_eq!(Day24::part1(&valley).to_string(), "none, the blizzards never let us through");
+        assert_eq!(Minutes(Some(18)).to_string(), "18");
+    }
+}
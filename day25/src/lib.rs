@@ -0,0 +1,140 @@
+use common::{Example, ParseError, Rng, Solution, Source};
+
+fn encode(mut n: isize) -> String {
+    let mut res = String::new();
+    let mut carry = 0;
+    loop {
+        let (ch, carry_next) = match (n + carry) % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            4 => ('-', 1),
+            _ => unreachable!(),
+        };
+        res.insert(0, ch);
+        n = (n + carry) / 5;
+        carry = carry_next;
+        if n == 0 && carry == 0 {
+            break;
+        }
+    }
+    res
+}
+
+/// The value of a SNAFU number, if it fits in an `isize`.
+fn decode(s: &str) -> Option<isize> {
+    s.chars().try_fold(0isize, |n, ch| {
+        n.checked_mul(5)?.checked_add(match ch {
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            '=' => -2,
+            '-' => -1,
+            _ => unreachable!(),
+        })
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+    let src = Source::new(input);
+    input.lines().map(|l| {
+        src.check_chars(l, |c| "210-=".contains(c), "a SNAFU digit")?;
+        decode(l).ok_or_else(|| src.error(l, "a SNAFU number small enough for an isize"))
+    }).collect()
+}
+
+pub fn puzzle1(numbers: &[isize]) -> String {
+    encode(numbers.iter().sum())
+}
+
+pub fn puzzle2(_numbers: &[isize]) -> u32 {
+    0
+}
+
+/// `size` SNAFU numbers up to about 10^12.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| {
+        let digits = rng.range(1..=12);
+        encode(rng.range(1..=10isize.pow(digits))) + "\n"
+    }).collect()
+}
+
+const INPUT: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Vec<isize>;
+    type Answer1 = String;
+    type Answer2 = u32;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example { name: "example", input: INPUT, answers: [Some("2=-1=0"), Some("0")] },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        puzzle1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        puzzle2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_puzzle1() {
+        assert_eq!(puzzle1(&parse(INPUT).unwrap()), "2=-1=0");
+    }
+
+    #[test]
+    fn test_puzzle2() {
+        assert_eq!(puzzle2(&parse(INPUT).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_snafu_round_trip() {
+        common::fuzz::check(0..1000, |rng| {
+            let n = rng.range(0..=isize::MAX);
+            assert_eq!(decode(&encode(n)), Some(n));
+            // without leading zeros, every string of SNAFU digits is a number's only encoding
+            let len = rng.range(0..=30);
+            let s = rng.word("12", 1) + &rng.word("210-=", len);
+            match decode(&s) {
+                Some(n) => assert_eq!(encode(n), s),
+                None => assert!(len >= 27),
+            }
+        });
+    }
+
+    #[test]
+    fn test_snafu_overflow() {
+        // 30 digits are worth more than an isize holds
+        assert!(parse("1---0==20-==2=10-01-210=-=22==").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::image::{self, Rgb};
+use common::{ParseError, Source};
+
+/// A `(row, col)` position, row 0 being the first line of the input.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: up, down, left, right.
+pub const DIRS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8 surrounding neighbours, row by row.
+pub const DIRS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parses one row per line, mapping every character with `cell`, which returns `None` for invalid ones.
+    ///
+    /// All rows must be as wide as the first.
+    pub fn parse(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let src = Source::new(input);
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        let mut grid = Grid { width, height: 0, cells: vec![] };
+        for line in input.lines() {
+            grid.push_row(src, line, expected, &cell)?;
+            if line.chars().count() != width {
+                return Err(src.error(line, format!("a row of {} cells", width)));
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Like [`Grid::parse`], but rows may have different lengths and are padded with `fill` to the widest one.
+    pub fn parse_padded(input: &str, fill: T, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let src = Source::new(input);
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut grid = Grid { width, height: 0, cells: vec![] };
+        for line in input.lines() {
+            grid.push_row(src, line, expected, &cell)?;
+            grid.cells.resize(grid.width * grid.height, fill.clone());
+        }
+        Ok(grid)
+    }
+
+    fn push_row(&mut self, src: Source, line: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<(), ParseError> {
+        for (i, c) in line.char_indices() {
+            self.cells.push(cell(c).ok_or_else(|| src.error(&line[i..i + c.len_utf8()], expected))?);
+        }
+        self.height += 1;
+        Ok(())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos).then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// The position `offset` away from `pos`, if it is inside the grid.
+    pub fn step(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The up to 8 surrounding neighbours of `pos` that are inside the grid, diagonals included.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// The cells of column `col`, top to bottom; none when the grid has no such column, as a grid 0 wide has none.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        let height = if col < self.width { self.height } else { 0 };
+        self.cells[col.min(self.cells.len())..].iter().step_by(self.width.max(1)).take(height)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, of a cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, c)| *c == value).map(|(pos, _)| pos)
+    }
+
+    /// All positions, row by row, of cells equal to `value`.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, c)| *c == value).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Renders the grid as text, one line per row, drawing every cell with `f`.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows().map(|row| row.iter().map(&f).collect::<String>()).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Renders the grid as a binary PPM picture, every cell a square of `scale` pixels of the color `f` gives it.
+    pub fn ppm(&self, scale: usize, f: impl Fn(&T) -> Rgb) -> Vec<u8> {
+        image::ppm(self.width, self.height, scale, |pos| f(&self[pos]))
+    }
+
+    /// Records the grid as the next frame of the simulation `name`, if frames are being recorded.
+    pub fn frame(&self, name: &str, f: impl Fn(&T) -> Rgb) {
+        image::frame(name, self.width, self.height, |pos| f(&self[pos]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const INPUT: &str = "#.#
+..#
+#..";
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, "'.' or '#'", |c| "#.".contains(c).then_some(c))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(1, 2)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(parse("#.#\n.x#").unwrap_err().to_string(), "line 2, column 2: expected '.' or '#', found \"x\"");
+        assert_eq!(parse("#.#\n.#").unwrap_err().to_string(), "line 2, column 1: expected a row of 3 cells, found \".#\"");
+
+        let padded = Grid::parse_padded(" .\n.#.", ' ', "a tile", Some).unwrap();
+        assert_eq!(padded.render(|&c| c), " . \n.#.");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse(INPUT).unwrap();
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 2)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.step((2, 2), (0, 1)), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = parse(INPUT).unwrap();
+        assert_eq!(grid.row(1), ['.', '.', '#']);
+        assert_eq!(grid.column(2).collect::<String>(), "##.");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "#.#");
+        assert_eq!(grid.find(&'.'), Some((0, 1)));
+        assert_eq!(grid.find_all(&'#').collect::<Vec<_>>(), [(0, 0), (0, 2), (1, 2), (2, 0)]);
+        grid[(0, 0)] = '.';
+        grid.row_mut(2).fill('#');
+        assert_eq!(grid.map(|&c| c == '#').render(|&b| if b { '1' } else { '0' }), "001\n001\n111");
+        let ppm = grid.ppm(1, |&c| if c == '#' { image::WHITE } else { image::BLACK });
+        assert_eq!(ppm[..11], *b"P6\n3 3\n255\n");
+        assert_eq!(ppm[11..20], [image::BLACK, image::BLACK, image::WHITE].concat());
+    }
+
+    #[test]
+    fn test_empty_columns() {
+        let grid = Grid::new(0, 3, '.');
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.column(0).next_back(), None);
+        let grid = parse(INPUT).unwrap();
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.column(usize::MAX).len(), 0);
+    }
+}